@@ -0,0 +1,19 @@
+//! Serialize a `Vector3` as three `f32`s instead of `f64`s, halving its size at the cost of precision.
+//!
+//! Use it on a field with `#[serde(with = "vector::compact_f32")]`
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Vector3;
+
+/// Serialize the vector as a tuple of **x**, **y**, **z** each cast to `f32`
+pub fn serialize<S: Serializer>(vector: &Vector3, serializer: S) -> Result<S::Ok, S::Error> {
+    (vector.x as f32, vector.y as f32, vector.z as f32).serialize(serializer)
+}
+
+/// Deserialize a tuple of three `f32`s in **x**, **y**, **z** order back into a vector
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vector3, D::Error> {
+    let (x, y, z): (f32, f32, f32) = Deserialize::deserialize(deserializer)?;
+
+    Ok(Vector3::new(x as f64, y as f64, z as f64))
+}
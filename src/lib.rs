@@ -20,9 +20,18 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "serde")]
+pub mod compact_f32;
+
 use core::array::TryFromSliceError;
-use core::ops::{Add, Sub, Mul, Div};
+use core::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign, Index, IndexMut};
 use core::cmp::PartialEq;
+use core::fmt;
+use core::num::ParseFloatError;
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+use std::time::Duration;
 
 #[cfg(not(feature = "std"))]
 use libm;
@@ -34,10 +43,58 @@ pub struct Vector3 {
     pub z: f64
 }
 
+/// Error returned when parsing a `Vector3` from a string fails
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseVector3Error {
+    /// The string isn't wrapped in `(` and `)`
+    MissingParentheses,
+    /// The string doesn't have exactly three comma separated axes, holds the number of axes found
+    WrongAxisCount(usize),
+    /// One of the axes isn't a valid number
+    InvalidAxis(ParseFloatError)
+}
+
+impl fmt::Display for ParseVector3Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseVector3Error::MissingParentheses => write!(f, "vector must be wrapped in parentheses"),
+            ParseVector3Error::WrongAxisCount(count) => write!(f, "expected 3 axes, found {}", count),
+            ParseVector3Error::InvalidAxis(error) => write!(f, "invalid axis: {}", error)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVector3Error {}
+
 #[allow(unused)]
 impl Vector3 {
-    #[no_mangle]
-    pub fn new(x: f64, y: f64, z: f64) -> Vector3 {
+    /// All axes set to `0`
+    pub const ZERO: Vector3 = Vector3::new_zero();
+    /// All axes set to `1`
+    pub const ONE: Vector3 = Vector3::new(1.0, 1.0, 1.0);
+    /// Unit vector along **+x**
+    pub const X: Vector3 = Vector3::new(1.0, 0.0, 0.0);
+    /// Unit vector along **+y**
+    pub const Y: Vector3 = Vector3::new(0.0, 1.0, 0.0);
+    /// Unit vector along **+z**
+    pub const Z: Vector3 = Vector3::new(0.0, 0.0, 1.0);
+
+    /// **+y**. The direction constants follow a right-handed, **y** up convention (as in OpenGL),
+    /// so `X × Y = Z` and `FORWARD` is **-z**
+    pub const UP: Vector3 = Self::Y;
+    /// **-y**
+    pub const DOWN: Vector3 = Vector3::new(0.0, -1.0, 0.0);
+    /// **-x**
+    pub const LEFT: Vector3 = Vector3::new(-1.0, 0.0, 0.0);
+    /// **+x**
+    pub const RIGHT: Vector3 = Self::X;
+    /// **-z**, see `UP` for the handedness convention
+    pub const FORWARD: Vector3 = Vector3::new(0.0, 0.0, -1.0);
+    /// **+z**
+    pub const BACK: Vector3 = Self::Z;
+
+    pub const fn new(x: f64, y: f64, z: f64) -> Vector3 {
         Vector3 {
             x,
             y,
@@ -45,11 +102,18 @@ impl Vector3 {
         }
     }
 
-    #[no_mangle]
-    pub fn new_zero() -> Vector3 {
+    pub const fn new_zero() -> Vector3 {
         Vector3 { x: 0.0, y: 0.0, z: 0.0 }
     }
 
+    /// Return the vector from the first three values of the slice. Order -> **x**, **y**, **z**.
+    /// Slices shorter than three values return an error
+    pub fn from_slice(slice: &[f64]) -> Result<Vector3, TryFromSliceError> {
+        let axes: [f64; 3] = slice.get(..3).unwrap_or(slice).try_into()?;
+
+        Ok(Vector3::from(axes))
+    }
+
     /// Return the vector from the memory representation in **big-endian** byte order. Order -> **x**, **y**, **z**
     pub fn from_be_bytes(bytes: [u8; 24]) -> Result<Vector3, TryFromSliceError> {
         Ok(Vector3 {
@@ -70,6 +134,46 @@ impl Vector3 {
         result
     }
 
+    /// Return the vector from the memory representation in **little-endian** byte order. Order -> **x**, **y**, **z**
+    pub fn from_le_bytes(bytes: [u8; 24]) -> Result<Vector3, TryFromSliceError> {
+        Ok(Vector3 {
+            x: f64::from_le_bytes(bytes[..8].try_into()?),
+            y: f64::from_le_bytes(bytes[8..16].try_into()?),
+            z: f64::from_le_bytes(bytes[16..].try_into()?)
+        })
+    }
+
+    /// Return the memory representation of this vector as a byte array in **little-endian** byte order. Order -> **x**, **y**, **z**
+    pub fn to_le_bytes(&self) -> [u8; 24] {
+        let mut result: [u8; 24] = [0; 24];
+
+        result[..8].clone_from_slice(&self.x.to_le_bytes());
+        result[8..16].clone_from_slice(&self.y.to_le_bytes());
+        result[16..].clone_from_slice(&self.z.to_le_bytes());
+
+        result
+    }
+
+    /// Return the vector from the memory representation in **native** byte order. Order -> **x**, **y**, **z**
+    pub fn from_ne_bytes(bytes: [u8; 24]) -> Result<Vector3, TryFromSliceError> {
+        Ok(Vector3 {
+            x: f64::from_ne_bytes(bytes[..8].try_into()?),
+            y: f64::from_ne_bytes(bytes[8..16].try_into()?),
+            z: f64::from_ne_bytes(bytes[16..].try_into()?)
+        })
+    }
+
+    /// Return the memory representation of this vector as a byte array in **native** byte order. Order -> **x**, **y**, **z**
+    pub fn to_ne_bytes(&self) -> [u8; 24] {
+        let mut result: [u8; 24] = [0; 24];
+
+        result[..8].clone_from_slice(&self.x.to_ne_bytes());
+        result[8..16].clone_from_slice(&self.y.to_ne_bytes());
+        result[16..].clone_from_slice(&self.z.to_ne_bytes());
+
+        result
+    }
+
     /// Get vector's length
     pub fn magnitude(&self) -> f64 {
         #[cfg(feature = "std")]
@@ -82,15 +186,33 @@ impl Vector3 {
     pub fn sqrt_magnitude(&self) -> f64 {
         self.dot(self)
     }
-    /// Normalize vector or set it's length to `1`, but keep the same direction
+    /// Normalize vector or set it's length to `1`, but keep the same direction.
+    /// **Note:** the zero vector has no direction and gives `NaN` on every axis,
+    /// use `.try_normalize()` or `.normalize_or_zero()` if the vector can be zero
     pub fn normalize(&self) -> Self {
         #[cfg(feature = "std")]
-        return (1.0 / (self.x * self.x + self.y * self.y + self.z + self.z).sqrt()) * *self;
+        return (1.0 / (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()) * *self;
 
         #[cfg(not(feature = "std"))]
         return (1.0 / libm::sqrt(self.x * self.x + self.y * self.y + self.z * self.z)) * *self;
 
     }
+    /// Same as `.normalize()`, but returns `None` if the length is `f64::EPSILON` or less
+    pub fn try_normalize(&self) -> Option<Self> {
+        let length: f64 = self.magnitude();
+
+        if length <= f64::EPSILON {
+            return None;
+        }
+
+        Some((1.0 / length) * *self)
+    }
+
+    /// Same as `.normalize()`, but returns the zero vector if the length is `f64::EPSILON` or less
+    pub fn normalize_or_zero(&self) -> Self {
+        self.try_normalize().unwrap_or_default()
+    }
+
     /// Raises each axis of the vector to a floating point power
     pub fn powf(&self, power: f64) -> Self {
         #[cfg(feature = "std")]
@@ -100,28 +222,80 @@ impl Vector3 {
         return Vector3 { x: libm::pow(self.x, power), y: libm::pow(self.y, power), z: libm::pow(self.z, power) };
     }
 
-    /// Get angle between two vectors in **degrees**
+    /// Get angle between two vectors in **degrees**, `NaN` if either is the zero vector
     pub fn angle_degrees(&self, rhs: &Self) -> f64 {
         let dot: f64 = self.dot(rhs);
         let magnitudes: (f64, f64) = (self.magnitude(), rhs.magnitude());
+        // rounding can push nearly parallel vectors slightly outside of acos' domain
+        let cos: f64 = (dot / (magnitudes.0 * magnitudes.1)).clamp(-1.0, 1.0);
 
         #[cfg(feature = "std")]
-        return f64::acos(dot / (magnitudes.0 * magnitudes.1)).to_degrees();
+        return f64::acos(cos).to_degrees();
 
         #[cfg(not(feature = "std"))]
-        return libm::acos(dot / (magnitudes.0 * magnitudes.1)).to_degrees();
+        return libm::acos(cos).to_degrees();
     }
 
-    /// Get angle between two vectors in **radians**
+    /// Get angle between two vectors in **radians**, `NaN` if either is the zero vector
     pub fn angle_radians(&self, rhs: &Self) -> f64 {
         let dot: f64 = self.dot(rhs);
         let magnitudes: (f64, f64) = (self.magnitude(), rhs.magnitude());
+        // rounding can push nearly parallel vectors slightly outside of acos' domain
+        let cos: f64 = (dot / (magnitudes.0 * magnitudes.1)).clamp(-1.0, 1.0);
+
+        #[cfg(feature = "std")]
+        return f64::acos(cos);
+
+        #[cfg(not(feature = "std"))]
+        return libm::acos(cos);
+    }
+
+    /// Same as `.angle_radians()`, but returns `None` if either length is `f64::EPSILON` or less,
+    /// since the zero vector has no direction to measure from
+    pub fn try_angle_radians(&self, rhs: &Self) -> Option<f64> {
+        if self.magnitude() <= f64::EPSILON || rhs.magnitude() <= f64::EPSILON {
+            return None;
+        }
+
+        Some(self.angle_radians(rhs))
+    }
+
+    /// Get signed angle in **radians** from this vector to `other`, in `(-π, π]`.
+    /// Positive when `self.cross(other)` points the same way as `axis`, so counterclockwise when viewed from the tip of `axis`
+    pub fn signed_angle_radians(&self, other: &Self, axis: &Self) -> f64 {
+        let angle: f64 = self.angle_radians(other);
+
+        if self.cross(other).dot(axis) < 0.0 { -angle } else { angle }
+    }
+
+    /// Same as `.signed_angle_radians()`, but in **degrees**, in `(-180, 180]`
+    pub fn signed_angle_degrees(&self, other: &Self, axis: &Self) -> f64 {
+        self.signed_angle_radians(other, axis).to_degrees()
+    }
+
+    /// Get the great circle distance in **radians** between two points on the unit sphere,
+    /// both vectors are expected to be **normalized**, on other spheres multiply by the radius.
+    /// Uses `atan2(|a×b|, a·b)`, which stays accurate for nearly identical or opposite points unlike `acos`
+    pub fn great_circle_distance(&self, other: &Self) -> f64 {
+        let sin: f64 = self.cross(other).magnitude();
+        let cos: f64 = self.dot(other);
 
         #[cfg(feature = "std")]
-        return f64::acos(dot / (magnitudes.0 * magnitudes.1));
+        return f64::atan2(sin, cos);
 
         #[cfg(not(feature = "std"))]
-        return libm::acos(dot / (magnitudes.0 * magnitudes.1));
+        return libm::atan2(sin, cos);
+    }
+
+    /// Get the point halfway along the great circle between two points on the unit sphere,
+    /// both vectors are expected to be **normalized**.
+    /// For antipodal points every great circle through them works, so the midpoint is undefined
+    /// and some point perpendicular to both is returned instead, same as `.slerp(other, 0.5)`
+    pub fn spherical_midpoint(&self, other: &Self) -> Self {
+        match (*self + *other).try_normalize() {
+            Some(midpoint) => midpoint,
+            None => self.slerp(other, 0.5)
+        }
     }
 
     /// Project on (or onto) vector 
@@ -134,6 +308,17 @@ impl Vector3 {
         *self - self.project(b)
     }
 
+    /// Project onto the plane through the origin with the given `normal`, which doesn't need to be normalized.
+    /// This is the same as `.reject(normal)`, named for the plane use case
+    pub fn project_on_plane(&self, normal: &Self) -> Self {
+        self.reject(normal)
+    }
+
+    /// Get the scalar triple product `self · (b × c)`, the signed volume of the parallelepiped spanned by the three vectors
+    pub fn scalar_triple(&self, b: &Self, c: &Self) -> f64 {
+        self.dot(&b.cross(c))
+    }
+
     pub fn dot(&self, rhs: &Self) -> f64 {
         (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
     }
@@ -147,6 +332,21 @@ impl Vector3 {
         )
     }
 
+    /// Get outer product of the two vectors as a 3x3 matrix, where `[i][j]` is `self[i] * b[j]`
+    pub fn outer_product(&self, b: &Self) -> [[f64; 3]; 3] {
+        let a: [f64; 3] = [self.x, self.y, self.z];
+        let b: [f64; 3] = [b.x, b.y, b.z];
+        let mut result: [[f64; 3]; 3] = [[0.0; 3]; 3];
+
+        for i in 0..3 {
+            for j in 0..3 {
+                result[i][j] = a[i] * b[j];
+            }
+        }
+
+        result
+    }
+
     /// Rounds the vector entrywise down to the nearest integer
     pub fn floor(&self) -> Self {
         #[cfg(feature = "std")]
@@ -181,60 +381,1680 @@ impl Vector3 {
         }
     }
 
-}
+    /// Rounds the vector entrywise to the nearest integer, with halfway cases rounded away from zero
+    pub fn round(&self) -> Self {
+        #[cfg(feature = "std")]
+        return Self {
+            x: self.x.round(),
+            y: self.y.round(),
+            z: self.z.round()
+        };
 
-impl Mul<Vector3> for f64 {
-    type Output = Vector3;
-    /// Multiply vector by the scalar value
-    fn mul(self, rhs: Vector3) -> Vector3 {
-        Vector3 { x: rhs.x * self, y: rhs.y * self, z: rhs.z * self }
+        #[cfg(not(feature = "std"))]
+        Self {
+            x: libm::round(self.x),
+            y: libm::round(self.y),
+            z: libm::round(self.z)
+        }
     }
-}
 
-impl Mul<f64> for Vector3 {
-    type Output = Vector3;
-    /// Multiply vector by the scalar value
-    fn mul(self, rhs: f64) -> Self {
-        Vector3 { x: rhs * self.x, y: rhs * self.y, z: rhs * self.z }
+    /// Rounds the vector entrywise towards zero to the nearest integer
+    pub fn trunc(&self) -> Self {
+        #[cfg(feature = "std")]
+        return Self {
+            x: self.x.trunc(),
+            y: self.y.trunc(),
+            z: self.z.trunc()
+        };
+
+        #[cfg(not(feature = "std"))]
+        Self {
+            x: libm::trunc(self.x),
+            y: libm::trunc(self.y),
+            z: libm::trunc(self.z)
+        }
     }
-}
 
-impl Div<f64> for Vector3 {
-    type Output = Vector3;
+    /// Entrywise absolute value, shared by the methods that compare axis magnitudes
+    fn abs(&self) -> Self {
+        #[cfg(feature = "std")]
+        return Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs()
+        };
 
-    fn div(self, b: f64) -> Self {
-        self * (1.0 / b)
+        #[cfg(not(feature = "std"))]
+        Self {
+            x: libm::fabs(self.x),
+            y: libm::fabs(self.y),
+            z: libm::fabs(self.z)
+        }
     }
-}
 
-impl Mul<Vector3> for Vector3 {
-    type Output = Vector3;
-    /// The entrywise product of A and B
-    fn mul(self, rhs: Vector3) -> Self {
-        Vector3 {
-            x: self.x * rhs.x,
-            y: self.y * rhs.y,
-            z: self.z * rhs.z
+    /// Reflect a position through the origin, which is the same as negating every axis
+    pub fn point_reflect_origin(&self) -> Self {
+        -*self
+    }
+
+    /// Quantize each axis from the `[min, max]` range into an integer in `[0, 2^bits - 1]`.
+    /// Axes outside of the range are clamped. Panics if `bits` isn't in `1..=32`
+    pub fn quantize(&self, min: &Self, max: &Self, bits: u32) -> [u32; 3] {
+        assert!((1..=32).contains(&bits), "bits must be in 1..=32");
+        let levels: f64 = ((1u64 << bits) - 1) as f64;
+        let quantize_axis = |value: f64, min: f64, max: f64| -> u32 {
+            let t: f64 = ((value - min) / (max - min)).clamp(0.0, 1.0);
+
+            #[cfg(feature = "std")]
+            return (t * levels).round() as u32;
+
+            #[cfg(not(feature = "std"))]
+            return libm::round(t * levels) as u32;
+        };
+
+        [
+            quantize_axis(self.x, min.x, max.x),
+            quantize_axis(self.y, min.y, max.y),
+            quantize_axis(self.z, min.z, max.z)
+        ]
+    }
+
+    /// Reverse of `.quantize()`, maps each integer in `[0, 2^bits - 1]` back into the `[min, max]` range.
+    /// Panics if `bits` isn't in `1..=32`
+    pub fn dequantize(quantized: [u32; 3], min: &Self, max: &Self, bits: u32) -> Self {
+        assert!((1..=32).contains(&bits), "bits must be in 1..=32");
+        let levels: f64 = ((1u64 << bits) - 1) as f64;
+        let dequantize_axis = |value: u32, min: f64, max: f64| -> f64 {
+            min + (value as f64 / levels) * (max - min)
+        };
+
+        Self {
+            x: dequantize_axis(quantized[0], min.x, max.x),
+            y: dequantize_axis(quantized[1], min.y, max.y),
+            z: dequantize_axis(quantized[2], min.z, max.z)
         }
     }
-}
 
-impl Add<Vector3> for Vector3 {
-    type Output = Vector3;
+    /// Sample keyframes sorted by timestamp at the time `at`, linearly interpolating between the two bracketing keys.
+    /// Times before the first or after the last key are clamped to that key. Returns `None` if there are no keys
+    #[cfg(feature = "std")]
+    pub fn interpolate_keyframes(keys: &[(Duration, Vector3)], at: Duration) -> Option<Vector3> {
+        let first: &(Duration, Vector3) = keys.first()?;
+        let last: &(Duration, Vector3) = keys.last()?;
 
-    fn add(self, v2: Vector3) -> Self {
-        Vector3 {
-            x: self.x + v2.x,
-            y: self.y + v2.y,
-            z: self.z + v2.z
+        if at <= first.0 {
+            return Some(first.1);
+        }
+        if at >= last.0 {
+            return Some(last.1);
+        }
+
+        for pair in keys.windows(2) {
+            let (start, end) = (&pair[0], &pair[1]);
+
+            if at >= start.0 && at <= end.0 {
+                let span: f64 = (end.0 - start.0).as_secs_f64();
+                if span == 0.0 {
+                    return Some(end.1);
+                }
+
+                let t: f64 = (at - start.0).as_secs_f64() / span;
+                return Some(start.1 + (end.1 - start.1) * t);
+            }
         }
+
+        Some(last.1)
     }
-}
 
-impl Sub<Vector3> for Vector3 {
-    type Output = Vector3;
-    /// The difference between A and B
-    fn sub(self, v2: Vector3) -> Self {
-        Vector3 {x: self.x - v2.x, y: self.y - v2.y, z: self.z - v2.z}
+    /// Same as `.normalize()`, but also returns the original length. The zero vector returns `(Vector3::new_zero(), 0.0)`
+    pub fn normalize_and_length(&self) -> (Self, f64) {
+        let length: f64 = self.magnitude();
+
+        if length == 0.0 {
+            return (Self::new_zero(), 0.0);
+        }
+
+        ((1.0 / length) * *self, length)
+    }
+
+    /// Get the point on the unit sphere at the given latitude and longitude in **radians**.
+    /// The north pole is **+z**, and longitude `0` on the equator is **+x**, increasing towards **+y**
+    pub fn from_lat_lon(lat_radians: f64, lon_radians: f64) -> Self {
+        #[cfg(feature = "std")]
+        return Self {
+            x: lat_radians.cos() * lon_radians.cos(),
+            y: lat_radians.cos() * lon_radians.sin(),
+            z: lat_radians.sin()
+        };
+
+        #[cfg(not(feature = "std"))]
+        Self {
+            x: libm::cos(lat_radians) * libm::cos(lon_radians),
+            y: libm::cos(lat_radians) * libm::sin(lon_radians),
+            z: libm::sin(lat_radians)
+        }
+    }
+
+    /// Reverse of `Vector3::from_lat_lon()`, returns `(latitude, longitude)` in **radians**.
+    /// The vector doesn't need to be unit length. At the poles the longitude is `0`
+    pub fn to_lat_lon(&self) -> (f64, f64) {
+        let horizontal: f64 = Self::new(self.x, self.y, 0.0).magnitude();
+
+        #[cfg(feature = "std")]
+        return (f64::atan2(self.z, horizontal), f64::atan2(self.y, self.x));
+
+        #[cfg(not(feature = "std"))]
+        return (libm::atan2(self.z, horizontal), libm::atan2(self.y, self.x));
+    }
+
+    /// Get the point on the infinite line through `origin` along `direction` closest to this point.
+    /// A zero length `direction` returns `origin`
+    pub fn closest_point_on_line(&self, origin: &Self, direction: &Self) -> Self {
+        let length_squared: f64 = direction.dot(direction);
+
+        if length_squared == 0.0 {
+            return *origin;
+        }
+
+        *origin + *direction * ((*self - *origin).dot(direction) / length_squared)
+    }
+
+    /// Get the point on the segment from `a` to `b` closest to this point.
+    /// A zero length segment returns `a`
+    pub fn closest_point_on_segment(&self, a: &Self, b: &Self) -> Self {
+        let ab: Vector3 = *b - *a;
+        let length_squared: f64 = ab.dot(&ab);
+
+        if length_squared == 0.0 {
+            return *a;
+        }
+
+        let t: f64 = ((*self - *a).dot(&ab) / length_squared).clamp(0.0, 1.0);
+        *a + ab * t
+    }
+
+    /// Get perpendicular distance from this point to the infinite line through `origin` along `direction`
+    pub fn distance_to_line(&self, origin: &Self, direction: &Self) -> f64 {
+        (*self - self.closest_point_on_line(origin, direction)).magnitude()
+    }
+
+    /// Get distance from this point to the segment from `a` to `b`
+    pub fn distance_to_segment(&self, a: &Self, b: &Self) -> f64 {
+        (*self - self.closest_point_on_segment(a, b)).magnitude()
+    }
+
+    /// Respond to a collision against a surface with the unit `normal`. The velocity along the normal is
+    /// reversed and scaled by `restitution`, and the velocity along the surface is scaled by `1 - friction`
+    pub fn collide(&self, normal: &Self, restitution: f64, friction: f64) -> Self {
+        let normal_part: Vector3 = self.project(normal);
+        let tangent_part: Vector3 = self.reject(normal);
+
+        normal_part * -restitution + tangent_part * (1.0 - friction)
+    }
+
+    /// Check if three points are (nearly) on the same line, meaning the magnitude of `(b - a) × (c - a)` is below `eps`
+    pub fn are_collinear(a: &Self, b: &Self, c: &Self, eps: f64) -> bool {
+        (*b - *a).cross(&(*c - *a)).magnitude() < eps
+    }
+
+    /// Sort the axes in ascending order. Also returns the sign of the applied permutation,
+    /// `1` for an even and `-1` for an odd number of swaps
+    pub fn sorted_with_parity(&self) -> (Self, i32) {
+        let mut axes: [f64; 3] = [self.x, self.y, self.z];
+        let mut parity: i32 = 1;
+
+        for (i, j) in [(0, 1), (1, 2), (0, 1)] {
+            if axes[i] > axes[j] {
+                axes.swap(i, j);
+                parity = -parity;
+            }
+        }
+
+        (Self::new(axes[0], axes[1], axes[2]), parity)
+    }
+
+    /// Get coordinates of this vector in the basis `e0`, `e1`, `e2`.
+    /// The basis is assumed to be **orthonormal**, otherwise the result is meaningless
+    pub fn coordinates_in_basis(&self, e0: &Self, e1: &Self, e2: &Self) -> Self {
+        Self::new(self.dot(e0), self.dot(e1), self.dot(e2))
+    }
+
+    /// Reverse of `.coordinates_in_basis()`, get the vector from its coordinates in the basis `e0`, `e1`, `e2`
+    pub fn from_basis_coords(coords: &Self, e0: &Self, e1: &Self, e2: &Self) -> Self {
+        *e0 * coords.x + *e1 * coords.y + *e2 * coords.z
+    }
+
+    /// GLSL's `step`, entrywise `0.0` if the axis is below the `edge` axis, otherwise `1.0`
+    pub fn step(&self, edge: &Self) -> Self {
+        let step_axis = |value: f64, edge: f64| -> f64 {
+            if value < edge { 0.0 } else { 1.0 }
+        };
+
+        Self {
+            x: step_axis(self.x, edge.x),
+            y: step_axis(self.y, edge.y),
+            z: step_axis(self.z, edge.z)
+        }
+    }
+
+    /// GLSL's `mix`, interpolate each axis towards `target` by the matching axis of `t`
+    pub fn mix(&self, target: &Self, t: &Self) -> Self {
+        Self {
+            x: self.x + (target.x - self.x) * t.x,
+            y: self.y + (target.y - self.y) * t.y,
+            z: self.z + (target.z - self.z) * t.z
+        }
+    }
+
+    /// GLSL's `faceforward`, flip this normal so it faces against `incident`.
+    /// Returns the normal as is if `reference · incident < 0`, otherwise the negated normal
+    pub fn faceforward(&self, incident: &Self, reference: &Self) -> Self {
+        if reference.dot(incident) < 0.0 {
+            *self
+        } else {
+            -*self
+        }
+    }
+
+    /// Blend three vertex normals with the barycentric coordinates `u` and `v` and normalize the result,
+    /// `n0` has the weight `1 - u - v`. Returns the zero vector if the normals cancel out
+    pub fn interpolate_normal(n0: &Self, n1: &Self, n2: &Self, u: f64, v: f64) -> Self {
+        (*n0 * (1.0 - u - v) + *n1 * u + *n2 * v).normalize_and_length().0
+    }
+
+    /// Rounds the vector entrywise to the nearest integer, with halfway cases rounded to the even integer
+    pub fn round_ties_even(&self) -> Self {
+        #[cfg(feature = "std")]
+        return Self {
+            x: self.x.round_ties_even(),
+            y: self.y.round_ties_even(),
+            z: self.z.round_ties_even()
+        };
+
+        #[cfg(not(feature = "std"))]
+        Self {
+            x: libm::rint(self.x),
+            y: libm::rint(self.y),
+            z: libm::rint(self.z)
+        }
+    }
+
+    /// Clamp the point entrywise into the axis-aligned box spanned by the `min` and `max` corners
+    pub fn clamp_to_aabb(&self, min: &Self, max: &Self) -> Self {
+        self.clamp(min, max)
+    }
+
+    /// Get the point on the triangle `a`, `b`, `c` closest to this point, including its edges and vertices.
+    /// Uses the Voronoi region method from Ericson's *Real-Time Collision Detection*.
+    /// Zero area triangles are treated as their closest edge
+    pub fn closest_point_on_triangle(&self, a: &Self, b: &Self, c: &Self) -> Self {
+        let ab: Vector3 = *b - *a;
+        let ac: Vector3 = *c - *a;
+
+        if ab.cross(&ac).sqrt_magnitude() == 0.0 {
+            let candidates: [Vector3; 3] = [
+                self.closest_point_on_segment(a, b),
+                self.closest_point_on_segment(b, c),
+                self.closest_point_on_segment(c, a)
+            ];
+            let mut closest: Vector3 = candidates[0];
+            for candidate in &candidates[1..] {
+                if (*candidate - *self).sqrt_magnitude() < (closest - *self).sqrt_magnitude() {
+                    closest = *candidate;
+                }
+            }
+            return closest;
+        }
+
+        let ap: Vector3 = *self - *a;
+        let d1: f64 = ab.dot(&ap);
+        let d2: f64 = ac.dot(&ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return *a;
+        }
+
+        let bp: Vector3 = *self - *b;
+        let d3: f64 = ab.dot(&bp);
+        let d4: f64 = ac.dot(&bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return *b;
+        }
+
+        let vc: f64 = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return *a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp: Vector3 = *self - *c;
+        let d5: f64 = ab.dot(&cp);
+        let d6: f64 = ac.dot(&cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return *c;
+        }
+
+        let vb: f64 = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return *a + ac * (d2 / (d2 - d6));
+        }
+
+        let va: f64 = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            return *b + (*c - *b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denom: f64 = 1.0 / (va + vb + vc);
+        *a + ab * (vb * denom) + ac * (vc * denom)
+    }
+
+    /// Get the tangent (first derivative) of the uniform Catmull-Rom segment between `p1` and `p2` at `t` in `[0, 1]`
+    pub fn catmull_rom_tangent(p0: &Self, p1: &Self, p2: &Self, p3: &Self, t: f64) -> Self {
+        let linear: Vector3 = *p2 - *p0;
+        let quadratic: Vector3 = *p0 * 2.0 - *p1 * 5.0 + *p2 * 4.0 - *p3;
+        let cubic: Vector3 = *p1 * 3.0 - *p0 - *p2 * 3.0 + *p3;
+
+        (linear + quadratic * (2.0 * t) + cubic * (3.0 * t * t)) * 0.5
+    }
+
+    /// Get offsets to the 6 face-adjacent cells of a grid cell, in the order **+x**, **-x**, **+y**, **-y**, **+z**, **-z**
+    pub fn neighbor_offsets_6() -> [Self; 6] {
+        [
+            Self::new(1.0, 0.0, 0.0),
+            Self::new(-1.0, 0.0, 0.0),
+            Self::new(0.0, 1.0, 0.0),
+            Self::new(0.0, -1.0, 0.0),
+            Self::new(0.0, 0.0, 1.0),
+            Self::new(0.0, 0.0, -1.0)
+        ]
+    }
+
+    /// Get offsets to all 26 cells surrounding a grid cell, including edge and corner neighbors
+    pub fn neighbor_offsets_26() -> [Self; 26] {
+        let mut result: [Vector3; 26] = [Self::new_zero(); 26];
+        let mut i: usize = 0;
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    if x == 0 && y == 0 && z == 0 {
+                        continue;
+                    }
+                    result[i] = Self::new(x as f64, y as f64, z as f64);
+                    i += 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Encode the vector as fixed-point integers with `fractional_bits` bits after the binary point,
+    /// rounding each axis to the nearest step of `2^-fractional_bits`.
+    /// Axes beyond about `±2^(63 - fractional_bits)` saturate to `i64::MIN` or `i64::MAX`.
+    /// Panics if `fractional_bits` is `64` or more
+    pub fn to_fixed(&self, fractional_bits: u32) -> [i64; 3] {
+        assert!(fractional_bits < 64, "fractional_bits must be less than 64");
+        let scale: f64 = (1u64 << fractional_bits) as f64;
+
+        #[cfg(feature = "std")]
+        return [
+            (self.x * scale).round() as i64,
+            (self.y * scale).round() as i64,
+            (self.z * scale).round() as i64
+        ];
+
+        #[cfg(not(feature = "std"))]
+        [
+            libm::round(self.x * scale) as i64,
+            libm::round(self.y * scale) as i64,
+            libm::round(self.z * scale) as i64
+        ]
+    }
+
+    /// Reverse of `.to_fixed()`, decode fixed-point integers with `fractional_bits` bits after the binary point.
+    /// Panics if `fractional_bits` is `64` or more
+    pub fn from_fixed(fixed: [i64; 3], fractional_bits: u32) -> Self {
+        assert!(fractional_bits < 64, "fractional_bits must be less than 64");
+        let scale: f64 = (1u64 << fractional_bits) as f64;
+
+        Self {
+            x: fixed[0] as f64 / scale,
+            y: fixed[1] as f64 / scale,
+            z: fixed[2] as f64 / scale
+        }
+    }
+
+    /// Remove the `reference` direction from this vector and normalize the rest (one Gram-Schmidt step).
+    /// Returns the zero vector if this vector is parallel to `reference`, that is if what's left is
+    /// within `1e-12` of this vector's length, so rounding noise isn't blown up into an arbitrary direction.
+    /// A zero `reference` has no direction to remove, so it also returns the zero vector
+    pub fn orthogonalize_against(&self, reference: &Self) -> Self {
+        if reference.sqrt_magnitude() == 0.0 {
+            return Self::new_zero();
+        }
+
+        let (direction, length) = self.reject(reference).normalize_and_length();
+
+        if length <= 1e-12 * self.magnitude() {
+            return Self::new_zero();
+        }
+
+        direction
+    }
+
+    /// Entrywise `self < other`
+    pub fn lt(&self, other: &Self) -> [bool; 3] {
+        [self.x < other.x, self.y < other.y, self.z < other.z]
+    }
+
+    /// Entrywise `self <= other`
+    pub fn le(&self, other: &Self) -> [bool; 3] {
+        [self.x <= other.x, self.y <= other.y, self.z <= other.z]
+    }
+
+    /// Entrywise `self > other`
+    pub fn gt(&self, other: &Self) -> [bool; 3] {
+        [self.x > other.x, self.y > other.y, self.z > other.z]
+    }
+
+    /// Entrywise `self >= other`
+    pub fn ge(&self, other: &Self) -> [bool; 3] {
+        [self.x >= other.x, self.y >= other.y, self.z >= other.z]
+    }
+
+    /// Entrywise `self == other`
+    pub fn eq_mask(&self, other: &Self) -> [bool; 3] {
+        [self.x == other.x, self.y == other.y, self.z == other.z]
+    }
+
+    /// Check if `pred` holds for every axis
+    pub fn all<F: Fn(f64) -> bool>(&self, pred: F) -> bool {
+        pred(self.x) && pred(self.y) && pred(self.z)
+    }
+
+    /// Check if `pred` holds for at least one axis
+    pub fn any<F: Fn(f64) -> bool>(&self, pred: F) -> bool {
+        pred(self.x) || pred(self.y) || pred(self.z)
+    }
+
+    /// Check if all three axes are within `eps` of each other, like a uniform scale
+    pub fn is_uniform(&self, eps: f64) -> bool {
+        let largest: f64 = self.x.max(self.y).max(self.z);
+        let smallest: f64 = self.x.min(self.y).min(self.z);
+
+        largest - smallest <= eps
+    }
+
+    /// Zero every axis whose matching entry in `allowed` is `false`
+    pub fn constrain_axes(&self, allowed: [bool; 3]) -> Self {
+        Self {
+            x: if allowed[0] { self.x } else { 0.0 },
+            y: if allowed[1] { self.y } else { 0.0 },
+            z: if allowed[2] { self.z } else { 0.0 }
+        }
+    }
+
+    /// Snap the direction to the closest of the six signed unit axes, picked by the largest absolute axis.
+    /// Ties prefer **x** over **y** over **z**. The zero vector returns the zero vector
+    pub fn snap_to_nearest_axis(&self) -> Self {
+        let abs: [f64; 3] = self.abs().to_array();
+
+        let sign = |value: f64| -> f64 { if value < 0.0 { -1.0 } else { 1.0 } };
+
+        if abs[0] == 0.0 && abs[1] == 0.0 && abs[2] == 0.0 {
+            Self::new_zero()
+        } else if abs[0] >= abs[1] && abs[0] >= abs[2] {
+            Self::new(sign(self.x), 0.0, 0.0)
+        } else if abs[1] >= abs[2] {
+            Self::new(0.0, sign(self.y), 0.0)
+        } else {
+            Self::new(0.0, 0.0, sign(self.z))
+        }
+    }
+
+    /// Move towards `target` by at most `max_distance`, landing exactly on `target` when it's within reach
+    pub fn move_towards(&self, target: &Self, max_distance: f64) -> Self {
+        let delta: Vector3 = *target - *self;
+        let distance: f64 = delta.magnitude();
+
+        if distance <= max_distance || distance == 0.0 {
+            return *target;
+        }
+
+        *self + delta * (max_distance / distance)
+    }
+
+    /// Smoothly move `current` towards `target` with a critically damped spring, like Unity's `SmoothDamp`.
+    /// `velocity` is the spring state and is updated in place, so pass the same one every frame.
+    /// `smooth_time` is roughly the time to reach the target, `dt` is the time step
+    pub fn smooth_damp(current: &Self, target: &Self, velocity: &mut Self, smooth_time: f64, dt: f64) -> Self {
+        let omega: f64 = 2.0 / smooth_time.max(1e-4);
+
+        #[cfg(feature = "std")]
+        let decay: f64 = (-omega * dt).exp();
+
+        #[cfg(not(feature = "std"))]
+        let decay: f64 = libm::exp(-omega * dt);
+
+        let change: Vector3 = *current - *target;
+        let temp: Vector3 = (*velocity + change * omega) * dt;
+
+        *velocity = (*velocity - temp * omega) * decay;
+        *target + (change + temp) * decay
+    }
+
+    /// Rescale each axis from the `[min, max]` range into `[0, 1]`, without clamping.
+    /// An axis where `min == max` has no range and maps to `0.0`
+    pub fn normalize_range(&self, min: &Self, max: &Self) -> Self {
+        let normalize_axis = |value: f64, min: f64, max: f64| -> f64 {
+            if max == min { 0.0 } else { (value - min) / (max - min) }
+        };
+
+        Self {
+            x: normalize_axis(self.x, min.x, max.x),
+            y: normalize_axis(self.y, min.y, max.y),
+            z: normalize_axis(self.z, min.z, max.z)
+        }
+    }
+
+    /// Pack this **unit** vector into two values in `[-1, 1]` using octahedral encoding
+    pub fn encode_octahedral(&self) -> (f64, f64) {
+        #[cfg(feature = "std")]
+        let abs = |value: f64| -> f64 { value.abs() };
+
+        #[cfg(not(feature = "std"))]
+        let abs = |value: f64| -> f64 { libm::fabs(value) };
+
+        let sign = |value: f64| -> f64 { if value < 0.0 { -1.0 } else { 1.0 } };
+        let l1_norm: f64 = abs(self.x) + abs(self.y) + abs(self.z);
+        let (x, y) = (self.x / l1_norm, self.y / l1_norm);
+
+        if self.z < 0.0 {
+            ((1.0 - abs(y)) * sign(x), (1.0 - abs(x)) * sign(y))
+        } else {
+            (x, y)
+        }
+    }
+
+    /// Reverse of `.encode_octahedral()`, unpack two values in `[-1, 1]` into a unit vector
+    pub fn decode_octahedral(encoded: (f64, f64)) -> Self {
+        #[cfg(feature = "std")]
+        let abs = |value: f64| -> f64 { value.abs() };
+
+        #[cfg(not(feature = "std"))]
+        let abs = |value: f64| -> f64 { libm::fabs(value) };
+
+        let z: f64 = 1.0 - abs(encoded.0) - abs(encoded.1);
+        let fold: f64 = if z < 0.0 { -z } else { 0.0 };
+        let unfold = |value: f64| -> f64 { if value < 0.0 { value + fold } else { value - fold } };
+
+        Self::new(unfold(encoded.0), unfold(encoded.1), z).normalize()
+    }
+
+    /// Project the point onto the plane through `plane_point` and push it `offset` along the **unit** `plane_normal`,
+    /// e.g. to place decals without z-fighting
+    pub fn project_onto_plane_offset(&self, plane_point: &Self, plane_normal: &Self, offset: f64) -> Self {
+        let distance: f64 = (*self - *plane_point).dot(plane_normal);
+
+        *self - *plane_normal * distance + *plane_normal * offset
+    }
+
+    /// Project the world position to 2:1 isometric screen space as `(x - z, (x + z) / 2 - y)`, with **y** as height
+    pub fn to_isometric(&self) -> (f64, f64) {
+        (self.x - self.z, (self.x + self.z) * 0.5 - self.y)
+    }
+
+    /// Reverse of `.to_isometric()`. The screen position alone can't tell the height apart from depth,
+    /// so the world height `y` must be given
+    pub fn from_isometric(screen: (f64, f64), y: f64) -> Self {
+        let sum: f64 = (screen.1 + y) * 2.0;
+
+        Self::new((sum + screen.0) * 0.5, y, (sum - screen.0) * 0.5)
+    }
+
+    /// Perspective divide of a camera space point onto the image plane at distance `focal_length`.
+    /// The camera sits at the origin looking down **-z** (same as `Vector3::FORWARD`) with **y** up,
+    /// so only points with `z < 0` are in front of it, anything else gives `None`
+    pub fn project_perspective(&self, focal_length: f64) -> Option<(f64, f64)> {
+        if self.z >= 0.0 {
+            return None;
+        }
+
+        let scale: f64 = focal_length / -self.z;
+
+        Some((self.x * scale, self.y * scale))
+    }
+
+    /// Cubic Hermite interpolation from `p0` with tangent `m0` to `p1` with tangent `m1` at `t` in `[0, 1]`
+    pub fn hermite(p0: &Self, m0: &Self, p1: &Self, m1: &Self, t: f64) -> Self {
+        let t2: f64 = t * t;
+        let t3: f64 = t2 * t;
+
+        let h00: f64 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10: f64 = t3 - 2.0 * t2 + t;
+        let h01: f64 = -2.0 * t3 + 3.0 * t2;
+        let h11: f64 = t3 - t2;
+
+        *p0 * h00 + *m0 * h10 + *p1 * h01 + *m1 * h11
+    }
+
+    /// Reflect only the **x** and **y** axes about the **xy** part of `normal`, keeping **z** exactly as is.
+    /// The normal doesn't need to be unit length, and a normal with no **xy** part returns the vector unchanged
+    pub fn reflect_planar_xy(&self, normal: &Self) -> Self {
+        let length_squared: f64 = normal.x * normal.x + normal.y * normal.y;
+
+        if length_squared == 0.0 {
+            return *self;
+        }
+
+        let scale: f64 = 2.0 * (self.x * normal.x + self.y * normal.y) / length_squared;
+
+        Self {
+            x: self.x - normal.x * scale,
+            y: self.y - normal.y * scale,
+            z: self.z
+        }
+    }
+
+    /// Get the average direction of `dirs` by summing and normalizing them, longer vectors weigh more.
+    /// Returns `None` for an empty slice, or when the directions cancel out (e.g. two opposing vectors),
+    /// that is when the sum is within `1e-12` of the total length of `dirs`, since the average direction is ambiguous then
+    pub fn mean_direction(dirs: &[Self]) -> Option<Self> {
+        let mut sum: Vector3 = Self::new_zero();
+        let mut total_length: f64 = 0.0;
+        for dir in dirs {
+            sum += *dir;
+            total_length += dir.magnitude();
+        }
+
+        let (direction, length) = sum.normalize_and_length();
+        if length <= 1e-12 * total_length {
+            return None;
+        }
+
+        Some(direction)
+    }
+
+    /// Store a rotation of `angle` radians about `axis` as a rotation vector, the unit axis scaled by the angle
+    pub fn to_rotation_vector(axis: &Self, angle: f64) -> Self {
+        axis.normalize_and_length().0 * angle
+    }
+
+    /// Reverse of `Vector3::to_rotation_vector()`, split this rotation vector into its unit axis and angle in radians.
+    /// A (nearly) zero rotation has no axis, so it returns `(Vector3::new_zero(), 0.0)`
+    pub fn from_rotation_vector(&self) -> (Self, f64) {
+        let (axis, angle) = self.normalize_and_length();
+
+        if angle < f64::EPSILON {
+            return (Self::new_zero(), 0.0);
+        }
+
+        (axis, angle)
+    }
+
+    /// Rotate the vector by `angle_radians` around `axis` using Rodrigues' rotation formula,
+    /// `axis` is expected to be **normalized**. Positive angles rotate counterclockwise when looking down the axis
+    pub fn rotate_around(&self, axis: &Self, angle_radians: f64) -> Self {
+        #[cfg(feature = "std")]
+        let (sin, cos): (f64, f64) = angle_radians.sin_cos();
+
+        #[cfg(not(feature = "std"))]
+        let (sin, cos): (f64, f64) = (libm::sin(angle_radians), libm::cos(angle_radians));
+
+        *self * cos + axis.cross(self) * sin + *axis * (axis.dot(self) * (1.0 - cos))
+    }
+
+    /// Clamp each axis into `[-limits, limits]` of the matching axis of `limits`
+    pub fn clamp_per_axis_abs(&self, limits: &Self) -> Self {
+        self.clamp_to_aabb(&-limits, limits)
+    }
+
+    /// Blend two RGB colors by converting them to HSV, interpolating along the shortest way around the hue circle
+    /// and converting back. Colors are expected to have **x**, **y**, **z** as red, green, blue in `[0, 1]`
+    pub fn lerp_hsv(a: &Self, b: &Self, t: f64) -> Self {
+        let hsv_a: Vector3 = a.rgb_to_hsv();
+        let hsv_b: Vector3 = b.rgb_to_hsv();
+
+        // Grays have no hue, so take the hue of the other color instead of blending towards red
+        let hue_a: f64 = if hsv_a.y == 0.0 { hsv_b.x } else { hsv_a.x };
+        let hue_b: f64 = if hsv_b.y == 0.0 { hsv_a.x } else { hsv_b.x };
+
+        let mut hue_delta: f64 = hue_b - hue_a;
+        if hue_delta > 0.5 {
+            hue_delta -= 1.0;
+        } else if hue_delta < -0.5 {
+            hue_delta += 1.0;
+        }
+
+        let mut hue: f64 = hue_a + hue_delta * t;
+        if hue < 0.0 {
+            hue += 1.0;
+        } else if hue >= 1.0 {
+            hue -= 1.0;
+        }
+
+        Self::new(
+            hue,
+            hsv_a.y + (hsv_b.y - hsv_a.y) * t,
+            hsv_a.z + (hsv_b.z - hsv_a.z) * t
+        ).hsv_to_rgb()
+    }
+
+    /// Convert RGB in `[0, 1]` to hue, saturation, value, all in `[0, 1]`
+    fn rgb_to_hsv(&self) -> Self {
+        let max: f64 = self.x.max(self.y).max(self.z);
+        let min: f64 = self.x.min(self.y).min(self.z);
+        let delta: f64 = max - min;
+
+        let mut hue: f64 = if delta == 0.0 {
+            0.0
+        } else if max == self.x {
+            (self.y - self.z) / delta
+        } else if max == self.y {
+            (self.z - self.x) / delta + 2.0
+        } else {
+            (self.x - self.y) / delta + 4.0
+        };
+        if hue < 0.0 {
+            hue += 6.0;
+        }
+
+        let saturation: f64 = if max == 0.0 { 0.0 } else { delta / max };
+
+        Self::new(hue / 6.0, saturation, max)
+    }
+
+    /// Convert hue, saturation, value in `[0, 1]` to RGB in `[0, 1]`
+    fn hsv_to_rgb(&self) -> Self {
+        let sector: f64 = self.x * 6.0;
+        let index: i64 = sector as i64;
+        let fraction: f64 = sector - index as f64;
+
+        let value: f64 = self.z;
+        let p: f64 = value * (1.0 - self.y);
+        let q: f64 = value * (1.0 - self.y * fraction);
+        let t: f64 = value * (1.0 - self.y * (1.0 - fraction));
+
+        match index % 6 {
+            0 => Self::new(value, t, p),
+            1 => Self::new(q, value, p),
+            2 => Self::new(p, value, t),
+            3 => Self::new(p, q, value),
+            4 => Self::new(t, p, value),
+            _ => Self::new(value, p, q)
+        }
+    }
+
+    /// HLSL's `saturate`, clamp each axis into `[0, 1]`
+    pub fn saturate(&self) -> Self {
+        self.clamp_to_aabb(&Self::new_zero(), &Self::new(1.0, 1.0, 1.0))
+    }
+
+    /// Reinhard tone mapping, entrywise `c / (1 + c)`. Axes are expected to be HDR values in `[0, ∞)`
+    pub fn reinhard(&self) -> Self {
+        Self {
+            x: self.x / (1.0 + self.x),
+            y: self.y / (1.0 + self.y),
+            z: self.z / (1.0 + self.z)
+        }
+    }
+
+    /// Extended Reinhard tone mapping, entrywise `c * (1 + c / white_point²) / (1 + c)`,
+    /// so that `white_point` maps to `1`. Axes are expected to be HDR values in `[0, ∞)`
+    pub fn reinhard_extended(&self, white_point: f64) -> Self {
+        let white_squared: f64 = white_point * white_point;
+        let tonemap_axis = |c: f64| -> f64 { c * (1.0 + c / white_squared) / (1.0 + c) };
+
+        Self {
+            x: tonemap_axis(self.x),
+            y: tonemap_axis(self.y),
+            z: tonemap_axis(self.z)
+        }
+    }
+
+    /// Get the `index`-th of `count` roughly evenly spread unit vectors on the sphere, using the golden angle spiral.
+    /// The points go from near **+y** at index `0` to near **-y** at the last index. A `count` of `0` returns the zero vector,
+    /// otherwise panics if `index` isn't less than `count`
+    pub fn fibonacci_sphere(index: usize, count: usize) -> Self {
+        if count == 0 {
+            return Self::new_zero();
+        }
+
+        assert!(index < count, "index must be less than count");
+
+        #[cfg(feature = "std")]
+        let golden_angle: f64 = core::f64::consts::PI * (3.0 - 5f64.sqrt());
+
+        #[cfg(not(feature = "std"))]
+        let golden_angle: f64 = core::f64::consts::PI * (3.0 - libm::sqrt(5.0));
+
+        let y: f64 = 1.0 - (2.0 * index as f64 + 1.0) / count as f64;
+        let theta: f64 = golden_angle * index as f64;
+
+        #[cfg(feature = "std")]
+        let (radius, cos, sin): (f64, f64, f64) = ((1.0 - y * y).sqrt(), theta.cos(), theta.sin());
+
+        #[cfg(not(feature = "std"))]
+        let (radius, cos, sin): (f64, f64, f64) = (libm::sqrt(1.0 - y * y), libm::cos(theta), libm::sin(theta));
+
+        Self::new(cos * radius, y, sin * radius)
+    }
+
+    /// Project this vector onto the tangent plane of a sphere at `surface_point`,
+    /// using `surface_point` as the outward normal direction
+    pub fn tangent_at(&self, surface_point: &Self) -> Self {
+        self.reject(surface_point)
+    }
+
+    /// Bilinear interpolation between four corners, where `c00` is at `(u, v) = (0, 0)`, `c10` at `(1, 0)`,
+    /// `c01` at `(0, 1)` and `c11` at `(1, 1)`
+    pub fn bilinear(c00: &Self, c10: &Self, c01: &Self, c11: &Self, u: f64, v: f64) -> Self {
+        *c00 * ((1.0 - u) * (1.0 - v))
+            + *c10 * (u * (1.0 - v))
+            + *c01 * ((1.0 - u) * v)
+            + *c11 * (u * v)
+    }
+
+    /// Trilinear interpolation between the eight corners of a cell. The corner at index `i` sits at
+    /// `(u, v, w) = (i & 1, (i >> 1) & 1, (i >> 2) & 1)`, so `corners[0]` is at `(0, 0, 0)`, `corners[1]` at `(1, 0, 0)`,
+    /// `corners[2]` at `(0, 1, 0)` and `corners[7]` at `(1, 1, 1)`
+    pub fn trilinear(corners: &[Self; 8], u: f64, v: f64, w: f64) -> Self {
+        let near: Vector3 = Self::bilinear(&corners[0], &corners[1], &corners[2], &corners[3], u, v);
+        let far: Vector3 = Self::bilinear(&corners[4], &corners[5], &corners[6], &corners[7], u, v);
+
+        near * (1.0 - w) + far * w
+    }
+
+    /// Get signed angle in **radians** from this vector to `other` in the **xy** plane, ignoring **z**.
+    /// Positive when turning counterclockwise (from **+x** towards **+y**), in `[-π, π]`
+    pub fn signed_angle_xy(&self, other: &Self) -> f64 {
+        let cross: f64 = self.x * other.y - self.y * other.x;
+        let dot: f64 = self.x * other.x + self.y * other.y;
+
+        #[cfg(feature = "std")]
+        return f64::atan2(cross, dot);
+
+        #[cfg(not(feature = "std"))]
+        return libm::atan2(cross, dot);
+    }
+
+    /// Rotate a quarter turn clockwise in the **xy** plane, keeping **z**. Exact, no trig involved
+    pub fn rotate_90_cw_xy(&self) -> Self {
+        Self::new(self.y, -self.x, self.z)
+    }
+
+    /// Rotate a quarter turn counterclockwise in the **xy** plane, keeping **z**. Exact, no trig involved
+    pub fn rotate_90_ccw_xy(&self) -> Self {
+        Self::new(-self.y, self.x, self.z)
+    }
+
+    /// Rotate a half turn in the **xy** plane, keeping **z**. Exact, no trig involved
+    pub fn rotate_180_xy(&self) -> Self {
+        Self::new(-self.x, -self.y, self.z)
+    }
+
+    /// Get which side of the directed edge from `edge_start` to `edge_end` the point is on in the **xy** plane, ignoring **z**.
+    /// Returns the signed 2D cross product: positive on the left, negative on the right and zero on the line
+    pub fn is_left_of_xy(point: &Self, edge_start: &Self, edge_end: &Self) -> f64 {
+        (edge_end.x - edge_start.x) * (point.y - edge_start.y) - (point.x - edge_start.x) * (edge_end.y - edge_start.y)
+    }
+
+    /// Get the 2D convex hull of the points in the **xy** plane with the monotone chain algorithm.
+    /// The hull vertices are returned in counterclockwise order starting from the lowest **x**, with **z** set to `0`.
+    /// Points lying on a hull edge aren't included
+    #[cfg(feature = "std")]
+    pub fn convex_hull_xy(points: &[Vector3]) -> Vec<Vector3> {
+        let mut sorted: Vec<Vector3> = points.iter().map(|point| Self::new(point.x, point.y, 0.0)).collect();
+        sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        sorted.dedup();
+
+        if sorted.len() < 3 {
+            return sorted;
+        }
+
+        let mut hull: Vec<Vector3> = Vec::with_capacity(sorted.len() + 1);
+
+        // Lower chain, left to right
+        for point in &sorted {
+            while hull.len() >= 2 && Self::is_left_of_xy(point, &hull[hull.len() - 2], &hull[hull.len() - 1]) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(*point);
+        }
+
+        // Upper chain, right to left
+        let lower_len: usize = hull.len() + 1;
+        for point in sorted.iter().rev().skip(1) {
+            while hull.len() >= lower_len && Self::is_left_of_xy(point, &hull[hull.len() - 2], &hull[hull.len() - 1]) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(*point);
+        }
+
+        // The last point is the first one again
+        hull.pop();
+        hull
+    }
+
+    /// Get distance between two points
+    pub fn distance(&self, other: &Self) -> f64 {
+        (*self - *other).magnitude()
+    }
+
+    /// Same as `.distance()`, but **not** sqrted
+    pub fn distance_squared(&self, other: &Self) -> f64 {
+        (*self - *other).sqrt_magnitude()
+    }
+
+    /// Get signed area of the polygon in the **xy** plane with the shoelace formula, ignoring **z**.
+    /// The area is positive when the vertices wind counterclockwise and negative when clockwise
+    pub fn polygon_area_xy(vertices: &[Vector3]) -> f64 {
+        let mut twice_area: f64 = 0.0;
+
+        for (i, a) in vertices.iter().enumerate() {
+            let b: &Vector3 = &vertices[(i + 1) % vertices.len()];
+            twice_area += a.x * b.y - b.x * a.y;
+        }
+
+        twice_area * 0.5
+    }
+
+    /// Get centroid of the polygon in the **xy** plane, ignoring **z** and with **z** set to `0` in the result.
+    /// Works with either winding. Returns `None` for polygons with zero area
+    pub fn polygon_centroid_xy(vertices: &[Vector3]) -> Option<Vector3> {
+        let area: f64 = Self::polygon_area_xy(vertices);
+
+        if area == 0.0 {
+            return None;
+        }
+
+        let mut sum: Vector3 = Self::new_zero();
+        for (i, a) in vertices.iter().enumerate() {
+            let b: &Vector3 = &vertices[(i + 1) % vertices.len()];
+            let cross: f64 = a.x * b.y - b.x * a.y;
+
+            sum.x += (a.x + b.x) * cross;
+            sum.y += (a.y + b.y) * cross;
+        }
+
+        Some(sum / (6.0 * area))
+    }
+
+    /// Linearly interpolate towards `target` by `t`. `t` isn't clamped, so values outside of `[0, 1]` extrapolate.
+    /// `t == 0.0` returns exactly this vector and `t == 1.0` returns exactly `target`
+    pub fn lerp(&self, target: &Self, t: f64) -> Self {
+        *self * (1.0 - t) + *target * t
+    }
+
+    /// Same as `.lerp()`, but `t` is clamped into `[0, 1]`
+    pub fn lerp_clamped(&self, target: &Self, t: f64) -> Self {
+        self.lerp(target, t.clamp(0.0, 1.0))
+    }
+
+    /// Spherical linear interpolation along the great circle arc from this vector to `target`,
+    /// both vectors are expected to be **normalized**. Falls back to `.lerp()` when the vectors
+    /// are nearly parallel, and when they're antiparallel (the arc isn't unique) rotates through
+    /// an arbitrary direction perpendicular to this vector
+    pub fn slerp(&self, target: &Self, t: f64) -> Self {
+        let theta: f64 = self.angle_radians(target);
+
+        #[cfg(feature = "std")]
+        let sin_theta: f64 = theta.sin();
+
+        #[cfg(not(feature = "std"))]
+        let sin_theta: f64 = libm::sin(theta);
+
+        // a zero vector gives NaN and lands here as well
+        if sin_theta.is_nan() || sin_theta <= 1e-6 {
+            if self.dot(target) >= 0.0 {
+                return self.lerp(target, t);
+            }
+
+            let helper: Vector3 = if self.abs().x < 0.9 { Self::X } else { Self::Y };
+            let perpendicular: Vector3 = helper.orthogonalize_against(self) * self.magnitude();
+            let angle: f64 = core::f64::consts::PI * t;
+
+            #[cfg(feature = "std")]
+            return *self * angle.cos() + perpendicular * angle.sin();
+
+            #[cfg(not(feature = "std"))]
+            return *self * libm::cos(angle) + perpendicular * libm::sin(angle);
+        }
+
+        #[cfg(feature = "std")]
+        let (from, to): (f64, f64) = (((1.0 - t) * theta).sin(), (t * theta).sin());
+
+        #[cfg(not(feature = "std"))]
+        let (from, to): (f64, f64) = (libm::sin((1.0 - t) * theta), libm::sin(t * theta));
+
+        (*self * from + *target * to) / sin_theta
+    }
+
+    /// Slerp each of the `from` vectors toward the same `target` by `t`, writing the results to `out`.
+    /// Same as calling `.slerp()` on every element, so the same **normalized** expectation applies
+    ///
+    /// Panics if `from` and `out` don't have the same length
+    pub fn slerp_all(from: &[Self], target: &Self, t: f64, out: &mut [Self]) {
+        assert_eq!(from.len(), out.len(), "from and out must have the same length");
+
+        for (start, interpolated) in from.iter().zip(out.iter_mut()) {
+            *interpolated = start.slerp(target, t);
+        }
+    }
+
+    /// Get the Householder reflection matrix `I - 2 * (v ⊗ v)` for this **unit** vector,
+    /// which reflects vectors about the plane perpendicular to it
+    pub fn householder_matrix(&self) -> [[f64; 3]; 3] {
+        let mut result: [[f64; 3]; 3] = self.outer_product(self);
+
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = if i == j { 1.0 } else { 0.0 } - 2.0 * *entry;
+            }
+        }
+
+        result
+    }
+
+    /// Offset each axis by a small deterministic amount in `[-amount, amount]` derived from `seed`.
+    /// The same seed always gives the same offset, no random number generator needed
+    pub fn jitter(&self, seed: u64, amount: f64) -> Self {
+        // SplitMix64 finalizer, with a different stream per axis
+        let offset = |stream: u64| -> f64 {
+            let mut hash: u64 = seed.wrapping_add(stream.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+            hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            hash ^= hash >> 31;
+
+            let unit: f64 = (hash >> 11) as f64 / (1u64 << 53) as f64;
+            (unit * 2.0 - 1.0) * amount
+        };
+
+        Self {
+            x: self.x + offset(1),
+            y: self.y + offset(2),
+            z: self.z + offset(3)
+        }
+    }
+
+    /// Reflect the vector off a surface with the given `normal`, which is expected to be **normalized**
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - 2.0 * self.dot(normal) * *normal
+    }
+
+    /// Reflect each of the `velocities` off its own **normalized** normal in `normals`, writing the results to `out`
+    ///
+    /// Panics if the three slices don't have the same length
+    pub fn reflect_pairwise(velocities: &[Self], normals: &[Self], out: &mut [Self]) {
+        assert_eq!(velocities.len(), normals.len(), "velocities and normals must have the same length");
+        assert_eq!(velocities.len(), out.len(), "velocities and out must have the same length");
+
+        for ((velocity, normal), reflected) in velocities.iter().zip(normals).zip(out.iter_mut()) {
+            *reflected = velocity.reflect(normal);
+        }
+    }
+
+    /// Get the winding number contribution of the polygon edge from `a` to `b` around the point in the **xy** plane, ignoring **z**.
+    /// Returns `1` for an upward crossing of the ray going right from the point with the point on the left of the edge,
+    /// `-1` for a downward crossing with the point on the right, and `0` otherwise.
+    /// Summing it over all edges of a polygon gives its winding number around the point, which is non-zero inside
+    pub fn edge_winding_xy(point: &Self, a: &Self, b: &Self) -> i32 {
+        if a.y <= point.y {
+            if b.y > point.y && Self::is_left_of_xy(point, a, b) > 0.0 {
+                return 1;
+            }
+        } else if b.y <= point.y && Self::is_left_of_xy(point, a, b) < 0.0 {
+            return -1;
+        }
+
+        0
+    }
+
+    /// Get the entrywise smaller axes of the two vectors. If one of the axes is `NaN`, the other one is returned
+    pub fn min(&self, other: &Self) -> Self {
+        #[cfg(feature = "std")]
+        return Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z)
+        };
+
+        #[cfg(not(feature = "std"))]
+        Self {
+            x: libm::fmin(self.x, other.x),
+            y: libm::fmin(self.y, other.y),
+            z: libm::fmin(self.z, other.z)
+        }
+    }
+
+    /// Get the entrywise larger axes of the two vectors. If one of the axes is `NaN`, the other one is returned
+    pub fn max(&self, other: &Self) -> Self {
+        #[cfg(feature = "std")]
+        return Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z)
+        };
+
+        #[cfg(not(feature = "std"))]
+        Self {
+            x: libm::fmax(self.x, other.x),
+            y: libm::fmax(self.y, other.y),
+            z: libm::fmax(self.z, other.z)
+        }
+    }
+
+    /// Clamp each axis into `[lo, hi]` of the matching axes. Unlike `f64::clamp` this doesn't panic if `lo > hi`, `hi` wins instead
+    pub fn clamp(&self, lo: &Self, hi: &Self) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    /// Get the signed coefficient of the vector along `unit_axis`, which is expected to be **normalized**.
+    /// Same as `.dot()`, but named for reading coefficients out of a vector
+    pub fn coefficient_along(&self, unit_axis: &Self) -> f64 {
+        self.dot(unit_axis)
+    }
+
+    /// Pack the vector into the 10-10-10-2 unsigned normalized format. Each axis is clamped into `[0, 1]` and stored as 10 bits,
+    /// **x** in the lowest bits, then **y** and **z**, with the lowest 2 bits of `w` in the highest bits
+    pub fn pack_unorm_1010102(&self, w: u8) -> u32 {
+        let scaled: Vector3 = (self.saturate() * 1023.0).round();
+
+        (scaled.x as u32) | ((scaled.y as u32) << 10) | ((scaled.z as u32) << 20) | (((w & 0b11) as u32) << 30)
+    }
+
+    /// Reverse of `.pack_unorm_1010102()`, unpack the vector and the 2 bit `w`
+    pub fn unpack_unorm_1010102(packed: u32) -> (Self, u8) {
+        let unpack_axis = |shift: u32| -> f64 { ((packed >> shift) & 0x3FF) as f64 / 1023.0 };
+
+        (Self::new(unpack_axis(0), unpack_axis(10), unpack_axis(20)), (packed >> 30) as u8)
+    }
+
+    /// Get relative error of this vector against `reference`, `|self - reference| / |reference|`.
+    /// With a zero `reference` the error is `0.0` if this vector is zero too, otherwise `f64::INFINITY`
+    pub fn relative_error(&self, reference: &Self) -> f64 {
+        let error: f64 = (*self - *reference).magnitude();
+        let length: f64 = reference.magnitude();
+
+        if length == 0.0 {
+            return if error == 0.0 { 0.0 } else { f64::INFINITY };
+        }
+
+        error / length
+    }
+
+    /// Replace every `NaN` or infinite axis with `0.0`, leaving finite axes untouched
+    pub fn sanitize(&self) -> Self {
+        let sanitize_axis = |value: f64| -> f64 { if value.is_finite() { value } else { 0.0 } };
+
+        Self {
+            x: sanitize_axis(self.x),
+            y: sanitize_axis(self.y),
+            z: sanitize_axis(self.z)
+        }
+    }
+
+    /// Get the entrywise absolute difference of the two vectors
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+
+    /// Check if every axis differs by at most `epsilon`
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.abs_diff(other).all(|diff| diff <= epsilon)
+    }
+
+    /// Check if every axis differs by at most `tolerance` times the larger absolute value of that axis,
+    /// which scales with the magnitude unlike `.approx_eq()`
+    pub fn approx_eq_relative(&self, other: &Self, tolerance: f64) -> bool {
+        let largest: Vector3 = self.abs().max(&other.abs());
+        let diff: Vector3 = self.abs_diff(other);
+
+        diff.x <= tolerance * largest.x && diff.y <= tolerance * largest.y && diff.z <= tolerance * largest.z
+    }
+
+    /// Linearly remap each axis from the `[in_min, in_max]` range to the `[out_min, out_max]` range.
+    /// The result isn't clamped, so values outside of the input range extrapolate.
+    /// Same as `.normalize_range()` for the input side, so an axis where `in_min == in_max` maps to `out_min`
+    pub fn remap(&self, in_min: &Self, in_max: &Self, out_min: &Self, out_max: &Self) -> Self {
+        *out_min + self.normalize_range(in_min, in_max) * (*out_max - *out_min)
+    }
+
+    /// Get the axes as an array. Order -> **x**, **y**, **z**
+    pub fn to_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Scale the vector down to a length of `max` if it's longer.
+    /// Also returns `true` if the original length exceeded `max` and the vector was clamped
+    pub fn clamp_magnitude_flagged(&self, max: f64) -> (Self, bool) {
+        let length: f64 = self.magnitude();
+
+        if length <= max {
+            return (*self, false);
+        }
+
+        (*self * (max / length), true)
+    }
+
+    /// Scale the whole vector down uniformly so its largest absolute axis is `max`, if that axis is larger.
+    /// Unlike per axis clamping the ratios between the axes are kept
+    pub fn clamp_largest_component(&self, max: f64) -> Self {
+        let abs: Vector3 = self.abs();
+        let largest: f64 = abs.x.max(abs.y).max(abs.z);
+
+        if largest <= max {
+            return *self;
+        }
+
+        *self * (max / largest)
+    }
+
+    /// Get the two axis indices of the principal plane this normal is most perpendicular to,
+    /// that is the two axes other than the largest absolute axis, in ascending order.
+    /// E.g. a normal along **z** returns `(0, 1)`, the **xy** plane. Ties prefer **x** over **y** over **z** as the dominant axis
+    pub fn dominant_plane(&self) -> (usize, usize) {
+        let abs: Vector3 = self.abs();
+
+        if abs.x >= abs.y && abs.x >= abs.z {
+            (1, 2)
+        } else if abs.y >= abs.z {
+            (0, 2)
+        } else {
+            (0, 1)
+        }
+    }
+
+    /// Rounds each axis to the nearest integer if it's within `tolerance` of it, other axes are left as is
+    ///
+    /// Useful for cleaning up drift from float accumulation on grid positions
+    pub fn snap_near_integers(&self, tolerance: f64) -> Self {
+        let rounded: Vector3 = self.round();
+        let diff: Vector3 = self.abs_diff(&rounded);
+        Self {
+            x: if diff.x <= tolerance { rounded.x } else { self.x },
+            y: if diff.y <= tolerance { rounded.y } else { self.y },
+            z: if diff.z <= tolerance { rounded.z } else { self.z }
+        }
+    }
+
+    /// Apply `f` to each axis
+    pub fn map<F: Fn(f64) -> f64>(&self, f: F) -> Self {
+        Self {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z)
+        }
+    }
+
+    /// Combine each axis with the same axis of `other` using `f`
+    pub fn zip_with<F: Fn(f64, f64) -> f64>(&self, other: &Self, f: F) -> Self {
+        Self {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z)
+        }
+    }
+
+}
+
+/// Get signed volume of the tetrahedron `a`, `b`, `c`, `d`. Positive when `d` is on the side of
+/// triangle `a`, `b`, `c` that its counterclockwise normal points to
+pub fn tetrahedron_volume(a: &Vector3, b: &Vector3, c: &Vector3, d: &Vector3) -> f64 {
+    (*b - *a).scalar_triple(&(*c - *a), &(*d - *a)) / 6.0
+}
+
+/// Get the ray parameter `t` where the ray hits the plane, the hit point is `ray_origin + ray_dir * t`.
+/// `t` is negative if the plane is behind the ray. Returns `None` if the ray is parallel to the plane
+pub fn ray_plane_intersection(ray_origin: &Vector3, ray_dir: &Vector3, plane_point: &Vector3, plane_normal: &Vector3) -> Option<f64> {
+    let denom: f64 = ray_dir.dot(plane_normal);
+
+    #[cfg(feature = "std")]
+    let parallel: bool = denom.abs() < f64::EPSILON;
+
+    #[cfg(not(feature = "std"))]
+    let parallel: bool = libm::fabs(denom) < f64::EPSILON;
+
+    if parallel {
+        return None;
+    }
+
+    Some((*plane_point - *ray_origin).dot(plane_normal) / denom)
+}
+
+/// Intersect the ray with the triangle `v0`, `v1`, `v2` using the Möller–Trumbore algorithm.
+/// Returns `(t, u, v)`, where the hit point is `origin + dir * t` and `u`, `v` are the barycentric weights of `v1` and `v2`.
+/// Returns `None` if the ray misses, is parallel to the triangle, or the triangle is behind the ray
+pub fn ray_triangle_intersection(origin: &Vector3, dir: &Vector3, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> Option<(f64, f64, f64)> {
+    let edge1: Vector3 = *v1 - *v0;
+    let edge2: Vector3 = *v2 - *v0;
+    let p: Vector3 = dir.cross(&edge2);
+    let det: f64 = edge1.dot(&p);
+
+    #[cfg(feature = "std")]
+    let parallel: bool = det.abs() < f64::EPSILON;
+
+    #[cfg(not(feature = "std"))]
+    let parallel: bool = libm::fabs(det) < f64::EPSILON;
+
+    if parallel {
+        return None;
+    }
+
+    let inv_det: f64 = 1.0 / det;
+    let s: Vector3 = *origin - *v0;
+    let u: f64 = s.dot(&p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q: Vector3 = s.cross(&edge1);
+    let v: f64 = dir.dot(&q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t: f64 = edge2.dot(&q) * inv_det;
+    if t < f64::EPSILON {
+        return None;
+    }
+
+    Some((t, u, v))
+}
+
+/// Get the unnormalized face normal `(b - a) × (c - a)` of the triangle `a`, `b`, `c`.
+/// Seen from the side it points to, `a`, `b`, `c` wind counterclockwise. Its length is twice the area
+pub fn triangle_normal(a: &Vector3, b: &Vector3, c: &Vector3) -> Vector3 {
+    (*b - *a).cross(&(*c - *a))
+}
+
+/// Same as `triangle_normal()`, but normalized. Zero area triangles return the zero vector,
+/// including (nearly) collinear ones whose normal is within `1e-12` of the product of the edge lengths
+pub fn triangle_normal_normalized(a: &Vector3, b: &Vector3, c: &Vector3) -> Vector3 {
+    let (normal, length) = triangle_normal(a, b, c).normalize_and_length();
+
+    if length <= 1e-12 * (*b - *a).magnitude() * (*c - *a).magnitude() {
+        return Vector3::new_zero();
+    }
+
+    normal
+}
+
+/// Streaming mean and variance of a sequence of vectors using Welford's algorithm, so samples don't have to be stored
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RunningStats {
+    count: u64,
+    mean: Vector3,
+    m2: Vector3
+}
+
+impl RunningStats {
+    /// Empty stats, same as `RunningStats::default()`
+    pub const fn new() -> Self {
+        RunningStats { count: 0, mean: Vector3::new_zero(), m2: Vector3::new_zero() }
+    }
+
+    /// Add a sample to the stats
+    pub fn push(&mut self, sample: &Vector3) {
+        self.count += 1;
+        let delta: Vector3 = *sample - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (*sample - self.mean);
+    }
+
+    /// Number of samples pushed so far
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Per axis mean of the samples, `None` if no samples were pushed
+    pub fn mean(&self) -> Option<Vector3> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(self.mean)
+    }
+
+    /// Per axis population variance of the samples, `None` if no samples were pushed
+    pub fn variance(&self) -> Option<Vector3> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(self.m2 / self.count as f64)
+    }
+}
+
+impl Mul<Vector3> for f64 {
+    type Output = Vector3;
+    /// Multiply vector by the scalar value
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        Vector3 { x: rhs.x * self, y: rhs.y * self, z: rhs.z * self }
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Vector3;
+    /// Multiply vector by the scalar value
+    fn mul(self, rhs: f64) -> Self {
+        Vector3 { x: rhs * self.x, y: rhs * self.y, z: rhs * self.z }
+    }
+}
+
+impl Div<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn div(self, b: f64) -> Self {
+        self * (1.0 / b)
+    }
+}
+
+impl Mul<Vector3> for Vector3 {
+    type Output = Vector3;
+    /// The entrywise product of A and B
+    fn mul(self, rhs: Vector3) -> Self {
+        Vector3 {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+            z: self.z * rhs.z
+        }
+    }
+}
+
+impl Div<Vector3> for Vector3 {
+    type Output = Vector3;
+    /// The entrywise quotient of A and B, a zero axis in B follows `f64` division
+    /// and gives `inf` (with the sign of A's axis), or `NaN` if A's axis is zero too
+    fn div(self, rhs: Vector3) -> Self {
+        Vector3 {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+            z: self.z / rhs.z
+        }
+    }
+}
+
+impl Add<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, v2: Vector3) -> Self {
+        Vector3 {
+            x: self.x + v2.x,
+            y: self.y + v2.y,
+            z: self.z + v2.z
+        }
+    }
+}
+
+impl Sub<Vector3> for Vector3 {
+    type Output = Vector3;
+    /// The difference between A and B
+    fn sub(self, v2: Vector3) -> Self {
+        Vector3 {x: self.x - v2.x, y: self.y - v2.y, z: self.z - v2.z}
+    }
+}
+
+impl AddAssign<Vector3> for Vector3 {
+    fn add_assign(&mut self, v2: Vector3) {
+        *self = *self + v2;
+    }
+}
+
+impl SubAssign<Vector3> for Vector3 {
+    fn sub_assign(&mut self, v2: Vector3) {
+        *self = *self - v2;
+    }
+}
+
+impl MulAssign<f64> for Vector3 {
+    /// Multiply vector by the scalar value in place
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Vector3> for Vector3 {
+    /// The entrywise product of A and B in place
+    fn mul_assign(&mut self, rhs: Vector3) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f64> for Vector3 {
+    fn div_assign(&mut self, b: f64) {
+        *self = *self / b;
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+    /// Flip the direction of the vector
+    fn neg(self) -> Self {
+        Vector3 { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl Neg for &Vector3 {
+    type Output = Vector3;
+    /// Flip the direction of the vector
+    fn neg(self) -> Vector3 {
+        -*self
+    }
+}
+
+impl Index<usize> for Vector3 {
+    type Output = f64;
+    /// Get axis by index, `0` -> **x**, `1` -> **y**, `2` -> **z**. Panics on any other index
+    fn index(&self, index: usize) -> &f64 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds: the len is 3 but the index is {}", index)
+        }
+    }
+}
+
+impl IndexMut<usize> for Vector3 {
+    /// Get mutable axis by index, `0` -> **x**, `1` -> **y**, `2` -> **z**. Panics on any other index
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of bounds: the len is 3 but the index is {}", index)
+        }
+    }
+}
+
+impl From<[f64; 3]> for Vector3 {
+    /// Order -> **x**, **y**, **z**
+    fn from(array: [f64; 3]) -> Self {
+        Vector3::new(array[0], array[1], array[2])
+    }
+}
+
+impl From<(f64, f64, f64)> for Vector3 {
+    /// Order -> **x**, **y**, **z**
+    fn from(tuple: (f64, f64, f64)) -> Self {
+        Vector3::new(tuple.0, tuple.1, tuple.2)
+    }
+}
+
+impl From<Vector3> for [f64; 3] {
+    /// Order -> **x**, **y**, **z**
+    fn from(vector: Vector3) -> Self {
+        vector.to_array()
+    }
+}
+
+impl From<Vector3> for (f64, f64, f64) {
+    /// Order -> **x**, **y**, **z**
+    fn from(vector: Vector3) -> Self {
+        (vector.x, vector.y, vector.z)
+    }
+}
+
+impl fmt::Display for Vector3 {
+    /// Format as `(x, y, z)`, which `FromStr` parses back
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl FromStr for Vector3 {
+    type Err = ParseVector3Error;
+    /// Parse the `(x, y, z)` format produced by `Display`, whitespace around the axes is ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner: &str = s.trim()
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or(ParseVector3Error::MissingParentheses)?;
+
+        let mut axes: [f64; 3] = [0.0; 3];
+        let mut count: usize = 0;
+
+        for axis in inner.split(',') {
+            if count < 3 {
+                axes[count] = axis.trim().parse().map_err(ParseVector3Error::InvalidAxis)?;
+            }
+            count += 1;
+        }
+
+        if count != 3 {
+            return Err(ParseVector3Error::WrongAxisCount(count));
+        }
+
+        Ok(Vector3::from(axes))
+    }
+}
+
+impl IntoIterator for Vector3 {
+    type Item = f64;
+    type IntoIter = core::array::IntoIter<f64, 3>;
+    /// Iterate over the axes in **x**, **y**, **z** order
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+impl IntoIterator for &Vector3 {
+    type Item = f64;
+    type IntoIter = core::array::IntoIter<f64, 3>;
+    /// Iterate over the axes in **x**, **y**, **z** order
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+impl core::iter::Sum<Vector3> for Vector3 {
+    fn sum<I: Iterator<Item = Vector3>>(iter: I) -> Self {
+        iter.fold(Vector3::new_zero(), |acc, vector| acc + vector)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Vector3> for Vector3 {
+    fn sum<I: Iterator<Item = &'a Vector3>>(iter: I) -> Self {
+        iter.fold(Vector3::new_zero(), |acc, vector| acc + *vector)
     }
 }
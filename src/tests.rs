@@ -6,6 +6,15 @@ fn default_values() {
     assert_eq!(zero, Vector3::new(0f64, 0f64, 0f64));
 }
 
+#[test]
+fn const_constructors() {
+    const GRAVITY: Vector3 = Vector3::new(0.0, -9.81, 0.0);
+    const ORIGIN: Vector3 = Vector3::new_zero();
+
+    assert_eq!(GRAVITY.y, -9.81);
+    assert_eq!(ORIGIN, Vector3::default());
+}
+
 #[test]
 fn to_bytes_and_back() {
     let vector_a = Vector3 {
@@ -21,6 +30,26 @@ fn to_bytes_and_back() {
     assert_eq!(vector_a, vector_b.unwrap());
 }
 
+#[test]
+fn to_le_bytes_and_back() {
+    let vector_a = Vector3 {
+        x: 4.0,
+        y: 4.0,
+        z: 4.0
+    };
+
+    assert_eq!(vec![0, 0, 0, 0, 0, 0, 16, 64, 0, 0, 0, 0, 0, 0, 16, 64, 0, 0, 0, 0, 0, 0, 16, 64], vector_a.to_le_bytes().to_vec());
+    assert_ne!(vector_a.to_le_bytes(), vector_a.to_be_bytes());
+
+    let bytes = vector_a.to_le_bytes();
+    let vector_b = Vector3::from_le_bytes(bytes);
+    assert_eq!(vector_a, vector_b.unwrap());
+
+    let bytes = vector_a.to_ne_bytes();
+    let vector_b = Vector3::from_ne_bytes(bytes);
+    assert_eq!(vector_a, vector_b.unwrap());
+}
+
 #[test]
 fn project() {
     let vector_a = Vector3 {
@@ -53,6 +82,7 @@ fn normalize() {
     let vec = Vector3::new(5.0, 0.0, 0.0);
 
     assert_eq!(vec.normalize(), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(Vector3::new(0.0, 0.0, 2.0).normalize(), Vector3::new(0.0, 0.0, 1.0));
 }
 
 #[test]
@@ -62,6 +92,18 @@ fn vector_normalization() {
     assert_eq!(Vector3 {x: 0.8944271909999159, y: 0.4472135954999579, z: 0.0}, v.normalize());
     assert_eq!(1.0, v.normalize().magnitude().round());
 }
+
+#[test]
+fn try_normalize() {
+    assert_eq!(Vector3::new_zero().try_normalize(), None);
+    assert_eq!(Vector3::new_zero().normalize_or_zero(), Vector3::new_zero());
+    assert!(Vector3::new_zero().normalize().x.is_nan());
+
+    let tiny = Vector3::new(0.0, 1e-15, 0.0);
+    assert_eq!(tiny.try_normalize(), Some(Vector3::new(0.0, 1.0, 0.0)));
+    assert_eq!(tiny.normalize_or_zero(), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(Vector3::new(0.0, 1e-17, 0.0).try_normalize(), None);
+}
 #[test]
 fn sub_two_vectors() {
     let vector1 = Vector3 {
@@ -125,7 +167,7 @@ fn angle_between() {
     };
 
     assert_eq!(vector_a.angle_degrees(&vector_b), 90.0);
-    assert_eq!(vector_a.angle_radians(&vector_b), 1.5707963267948966);
+    assert_eq!(vector_a.angle_radians(&vector_b), core::f64::consts::FRAC_PI_2);
 }
 
 #[test]
@@ -136,6 +178,14 @@ fn cross_product() {
     assert_eq!(vector_a.cross(&vector_b), Vector3::new(3.0, 3.0, -3.0));
 }
 
+#[test]
+fn outer_product() {
+    let vector_a = Vector3::new(1.0, 0.0, 0.0);
+    let vector_b = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_eq!(vector_a.outer_product(&vector_b), [[0.0, 1.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+}
+
 #[test]
 fn floor_it() {
     let vector = Vector3::new(5.3, 2.1, 5.4);
@@ -148,4 +198,1276 @@ fn ceil_it() {
     let vector = Vector3::new(5.7, 2.6, 5.5);
 
     assert_eq!(vector.ceil(), Vector3::new(6.0, 3.0, 6.0));
-}
\ No newline at end of file
+}
+
+#[test]
+fn round_it() {
+    let vector = Vector3::new(5.3, 2.5, -5.5);
+
+    assert_eq!(vector.round(), Vector3::new(5.0, 3.0, -6.0));
+}
+
+#[test]
+fn trunc_it() {
+    let vector = Vector3::new(5.7, 2.1, -5.7);
+
+    assert_eq!(vector.trunc(), Vector3::new(5.0, 2.0, -5.0));
+}
+
+#[test]
+fn point_reflect_origin() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(vector.point_reflect_origin(), Vector3::new(-1.0, -2.0, -3.0));
+}
+
+#[test]
+fn quantize_and_back() {
+    let min = Vector3::new(-10.0, -10.0, -10.0);
+    let max = Vector3::new(10.0, 10.0, 10.0);
+    let vector = Vector3::new(1.234, -5.678, 9.999);
+    let step: f64 = 20.0 / 65535.0;
+
+    let quantized = vector.quantize(&min, &max, 16);
+    let result = Vector3::dequantize(quantized, &min, &max, 16);
+
+    assert!((result.x - vector.x).abs() <= step);
+    assert!((result.y - vector.y).abs() <= step);
+    assert!((result.z - vector.z).abs() <= step);
+
+    let clamped = Vector3::new(-20.0, 20.0, 0.0).quantize(&min, &max, 16);
+    assert_eq!(clamped[0], 0);
+    assert_eq!(clamped[1], 65535);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn interpolate_keyframes() {
+    use std::time::Duration;
+
+    let keys = [
+        (Duration::from_secs(1), Vector3::new(0.0, 0.0, 0.0)),
+        (Duration::from_secs(3), Vector3::new(4.0, 2.0, 0.0)),
+        (Duration::from_secs(4), Vector3::new(4.0, 2.0, 8.0))
+    ];
+
+    assert_eq!(Vector3::interpolate_keyframes(&keys, Duration::from_secs(0)), Some(Vector3::new(0.0, 0.0, 0.0)));
+    assert_eq!(Vector3::interpolate_keyframes(&keys, Duration::from_secs(2)), Some(Vector3::new(2.0, 1.0, 0.0)));
+    assert_eq!(Vector3::interpolate_keyframes(&keys, Duration::from_millis(3500)), Some(Vector3::new(4.0, 2.0, 4.0)));
+    assert_eq!(Vector3::interpolate_keyframes(&keys, Duration::from_secs(10)), Some(Vector3::new(4.0, 2.0, 8.0)));
+    assert_eq!(Vector3::interpolate_keyframes(&[], Duration::from_secs(1)), None);
+}
+
+#[test]
+fn normalize_and_length() {
+    let vector = Vector3::new(3.0, 4.0, 0.0);
+
+    assert_eq!(vector.normalize_and_length(), (Vector3::new(0.6000000000000001, 0.8, 0.0), 5.0));
+    assert_eq!(Vector3::new_zero().normalize_and_length(), (Vector3::new_zero(), 0.0));
+}
+
+#[test]
+fn lat_lon_and_back() {
+    let (lat, lon) = (0.1, 1.2);
+    let vector = Vector3::from_lat_lon(lat, lon);
+
+    assert!((vector.magnitude() - 1.0).abs() < 1e-12);
+
+    let result = vector.to_lat_lon();
+    assert!((result.0 - lat).abs() < 1e-12);
+    assert!((result.1 - lon).abs() < 1e-12);
+
+    assert_eq!(Vector3::new(0.0, 0.0, 1.0).to_lat_lon(), (core::f64::consts::FRAC_PI_2, 0.0));
+}
+
+#[test]
+fn distance_to_line_and_segment() {
+    let a = Vector3::new(0.0, 0.0, 0.0);
+    let b = Vector3::new(4.0, 0.0, 0.0);
+
+    let beside = Vector3::new(2.0, 3.0, 0.0);
+    assert_eq!(beside.closest_point_on_segment(&a, &b), Vector3::new(2.0, 0.0, 0.0));
+    assert_eq!(beside.distance_to_segment(&a, &b), 3.0);
+    assert_eq!(beside.distance_to_line(&a, &b), 3.0);
+
+    let beyond = Vector3::new(7.0, 4.0, 0.0);
+    assert_eq!(beyond.closest_point_on_segment(&a, &b), b);
+    assert_eq!(beyond.distance_to_segment(&a, &b), 5.0);
+    assert_eq!(beyond.distance_to_line(&a, &b), 4.0);
+
+    assert_eq!(beside.distance_to_segment(&a, &a), beside.magnitude());
+    assert_eq!(beside.distance_to_line(&a, &Vector3::new_zero()), beside.magnitude());
+}
+
+#[test]
+fn tetrahedron_volume() {
+    let a = Vector3::new(0.0, 0.0, 0.0);
+    let b = Vector3::new(1.0, 0.0, 0.0);
+    let c = Vector3::new(0.0, 1.0, 0.0);
+    let d = Vector3::new(0.0, 0.0, 1.0);
+
+    assert_eq!(super::tetrahedron_volume(&a, &b, &c, &d), 1.0 / 6.0);
+    assert_eq!(super::tetrahedron_volume(&a, &c, &b, &d), -1.0 / 6.0);
+}
+
+#[test]
+fn collide() {
+    let velocity = Vector3::new(2.0, -5.0, 0.0);
+    let normal = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_eq!(velocity.collide(&normal, 0.8, 0.1), Vector3::new(1.8, 4.0, 0.0));
+}
+
+#[test]
+fn are_collinear() {
+    let a = Vector3::new(0.0, 0.0, 0.0);
+    let b = Vector3::new(1.0, 1.0, 1.0);
+
+    assert!(Vector3::are_collinear(&a, &b, &Vector3::new(3.0, 3.0, 3.0), 1e-9));
+    assert!(!Vector3::are_collinear(&a, &b, &Vector3::new(3.0, 0.0, 3.0), 1e-9));
+}
+
+#[test]
+fn sorted_with_parity() {
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).sorted_with_parity(), (Vector3::new(1.0, 2.0, 3.0), 1));
+    assert_eq!(Vector3::new(2.0, 1.0, 3.0).sorted_with_parity(), (Vector3::new(1.0, 2.0, 3.0), -1));
+    assert_eq!(Vector3::new(3.0, 1.0, 2.0).sorted_with_parity(), (Vector3::new(1.0, 2.0, 3.0), 1));
+    assert_eq!(Vector3::new(3.0, 2.0, 1.0).sorted_with_parity(), (Vector3::new(1.0, 2.0, 3.0), -1));
+}
+
+#[test]
+fn coordinates_in_basis() {
+    let vector = Vector3::new(1.5, -4.3, 2.7);
+    let (e0, e1, e2) = (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+
+    assert_eq!(vector.coordinates_in_basis(&e0, &e1, &e2), vector);
+}
+
+#[test]
+fn basis_coords_and_back() {
+    let vector = Vector3::new(1.5, -4.3, 2.7);
+    let half_sqrt: f64 = core::f64::consts::FRAC_1_SQRT_2;
+    let e0 = Vector3::new(half_sqrt, half_sqrt, 0.0);
+    let e1 = Vector3::new(-half_sqrt, half_sqrt, 0.0);
+    let e2 = Vector3::new(0.0, 0.0, 1.0);
+
+    let coords = vector.coordinates_in_basis(&e0, &e1, &e2);
+    let result = Vector3::from_basis_coords(&coords, &e0, &e1, &e2);
+
+    assert!((result - vector).magnitude() < 1e-12);
+}
+
+#[test]
+fn step() {
+    let vector = Vector3::new(0.3, 0.6, 0.9);
+    let edge = Vector3::new(0.5, 0.5, 0.5);
+
+    assert_eq!(vector.step(&edge), Vector3::new(0.0, 1.0, 1.0));
+}
+
+#[test]
+fn mix() {
+    let vector_a = Vector3::new(0.0, 2.0, 4.0);
+    let vector_b = Vector3::new(10.0, 6.0, 8.0);
+
+    assert_eq!(vector_a.mix(&vector_b, &Vector3::new(0.0, 0.5, 1.0)), Vector3::new(0.0, 4.0, 8.0));
+}
+
+#[test]
+fn faceforward() {
+    let normal = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_eq!(normal.faceforward(&Vector3::new(1.0, -1.0, 0.0), &normal), normal);
+    assert_eq!(normal.faceforward(&Vector3::new(1.0, 1.0, 0.0), &normal), Vector3::new(0.0, -1.0, 0.0));
+}
+
+#[test]
+fn interpolate_normal() {
+    let normal = Vector3::new(0.0, 0.0, 1.0);
+
+    assert_eq!(Vector3::interpolate_normal(&normal, &normal, &normal, 0.25, 0.5), normal);
+
+    let opposite = Vector3::new(0.0, 0.0, -1.0);
+    assert_eq!(Vector3::interpolate_normal(&normal, &opposite, &normal, 0.5, 0.0), Vector3::new_zero());
+}
+
+#[test]
+fn round_ties_even_it() {
+    let vector = Vector3::new(0.5, 1.5, 2.5);
+
+    assert_eq!(vector.round_ties_even(), Vector3::new(0.0, 2.0, 2.0));
+}
+
+#[test]
+fn clamp_to_aabb() {
+    let min = Vector3::new(-1.0, 0.0, -1.0);
+    let max = Vector3::new(1.0, 2.0, 1.0);
+
+    assert_eq!(Vector3::new(5.0, -3.0, 0.5).clamp_to_aabb(&min, &max), Vector3::new(1.0, 0.0, 0.5));
+}
+
+#[test]
+fn closest_point_on_triangle() {
+    let a = Vector3::new(0.0, 0.0, 0.0);
+    let b = Vector3::new(4.0, 0.0, 0.0);
+    let c = Vector3::new(0.0, 4.0, 0.0);
+
+    let above_face = Vector3::new(1.0, 1.0, 5.0);
+    assert_eq!(above_face.closest_point_on_triangle(&a, &b, &c), Vector3::new(1.0, 1.0, 0.0));
+
+    let beside_edge = Vector3::new(2.0, -3.0, 1.0);
+    assert_eq!(beside_edge.closest_point_on_triangle(&a, &b, &c), Vector3::new(2.0, 0.0, 0.0));
+
+    let past_hypotenuse = Vector3::new(3.0, 3.0, 0.0);
+    assert_eq!(past_hypotenuse.closest_point_on_triangle(&a, &b, &c), Vector3::new(2.0, 2.0, 0.0));
+
+    let past_vertex = Vector3::new(-2.0, -2.0, 0.0);
+    assert_eq!(past_vertex.closest_point_on_triangle(&a, &b, &c), a);
+
+    let degenerate = Vector3::new(2.0, 0.0, 0.0);
+    assert_eq!(Vector3::new(3.0, 1.0, 0.0).closest_point_on_triangle(&a, &b, &degenerate), Vector3::new(3.0, 0.0, 0.0));
+}
+
+#[test]
+fn ray_plane_intersection() {
+    let plane_point = Vector3::new(0.0, 0.0, -5.0);
+    let plane_normal = Vector3::new(0.0, 0.0, 1.0);
+    let origin = Vector3::new(1.0, 2.0, 0.0);
+
+    assert_eq!(super::ray_plane_intersection(&origin, &Vector3::new(0.0, 0.0, -2.0), &plane_point, &plane_normal), Some(2.5));
+    assert_eq!(super::ray_plane_intersection(&origin, &Vector3::new(1.0, 1.0, 0.0), &plane_point, &plane_normal), None);
+}
+
+#[test]
+fn ray_triangle_intersection() {
+    let v0 = Vector3::new(0.0, 0.0, 0.0);
+    let v1 = Vector3::new(3.0, 0.0, 0.0);
+    let v2 = Vector3::new(0.0, 3.0, 0.0);
+    let down = Vector3::new(0.0, 0.0, -1.0);
+
+    assert_eq!(super::ray_triangle_intersection(&Vector3::new(1.0, 1.0, 2.0), &down, &v0, &v1, &v2), Some((2.0, 1.0 / 3.0, 1.0 / 3.0)));
+    assert_eq!(super::ray_triangle_intersection(&Vector3::new(5.0, 5.0, 2.0), &down, &v0, &v1, &v2), None);
+    assert_eq!(super::ray_triangle_intersection(&Vector3::new(1.0, 1.0, 2.0), &Vector3::new(1.0, 0.0, 0.0), &v0, &v1, &v2), None);
+}
+
+#[test]
+fn catmull_rom_tangent() {
+    let (p0, p1, p2, p3) = (
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 0.0),
+        Vector3::new(3.0, 3.0, 1.0),
+        Vector3::new(4.0, 1.0, 2.0)
+    );
+    let position = |t: f64| -> Vector3 {
+        (p1 * 2.0
+            + (p2 - p0) * t
+            + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * (t * t)
+            + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * (t * t * t)) * 0.5
+    };
+    let h: f64 = 1e-6;
+
+    for t in [0.0, 0.3, 0.5, 1.0] {
+        let approximate = (position(t + h) - position(t - h)) / (2.0 * h);
+        let tangent = Vector3::catmull_rom_tangent(&p0, &p1, &p2, &p3, t);
+
+        assert!((tangent - approximate).magnitude() < 1e-6);
+    }
+}
+
+#[test]
+fn neighbor_offsets() {
+    let offsets_6 = Vector3::neighbor_offsets_6();
+    let offsets_26 = Vector3::neighbor_offsets_26();
+
+    assert_eq!(offsets_6.len(), 6);
+    assert_eq!(offsets_26.len(), 26);
+
+    for (i, a) in offsets_26.iter().enumerate() {
+        assert_ne!(*a, Vector3::new_zero());
+        for b in &offsets_26[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+    for (i, a) in offsets_6.iter().enumerate() {
+        assert_eq!(a.magnitude(), 1.0);
+        assert!(offsets_26.contains(a));
+        for b in &offsets_6[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn compact_f32_json() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Entity {
+        #[serde(with = "crate::compact_f32")]
+        position: Vector3
+    }
+
+    let entity = Entity { position: Vector3::new(1.5, -0.1, 3.0) };
+    let json = serde_json::to_string(&entity).unwrap();
+    assert_eq!(json, r#"{"position":[1.5,-0.1,3.0]}"#);
+
+    let result: Entity = serde_json::from_str(&json).unwrap();
+    assert_eq!(result.position, Vector3::new(1.5, -0.1f32 as f64, 3.0));
+    assert_ne!(result.position, entity.position);
+}
+
+#[test]
+fn fixed_point_and_back() {
+    let vector = Vector3::new(1.2345678, -98.7654321, 0.00001);
+    let step: f64 = 1.0 / 65536.0;
+
+    let fixed = vector.to_fixed(16);
+    assert_eq!(fixed, [80909, -6472691, 1]);
+
+    let result = Vector3::from_fixed(fixed, 16);
+    assert!((result.x - vector.x).abs() <= step / 2.0);
+    assert!((result.y - vector.y).abs() <= step / 2.0);
+    assert!((result.z - vector.z).abs() <= step / 2.0);
+}
+
+#[test]
+fn orthogonalize_against() {
+    let reference = Vector3::new(1.0, 0.0, 0.0);
+
+    assert_eq!(Vector3::new(1.0, 1.0, 0.0).orthogonalize_against(&reference), Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(Vector3::new(3.0, 0.0, 0.0).orthogonalize_against(&reference), Vector3::new_zero());
+
+    let vector = Vector3::new(1.1, 2.3, 3.7);
+    assert_eq!(vector.orthogonalize_against(&(vector * 3.3)), Vector3::new_zero());
+    assert_eq!(vector.orthogonalize_against(&Vector3::ZERO), Vector3::new_zero());
+}
+
+#[test]
+fn componentwise_compare() {
+    let vector_a = Vector3::new(1.0, 5.0, 3.0);
+    let vector_b = Vector3::new(2.0, 4.0, 3.0);
+
+    assert_eq!(vector_a.lt(&vector_b), [true, false, false]);
+    assert_eq!(vector_a.le(&vector_b), [true, false, true]);
+    assert_eq!(vector_a.gt(&vector_b), [false, true, false]);
+    assert_eq!(vector_a.ge(&vector_b), [false, true, true]);
+    assert_eq!(vector_a.eq_mask(&vector_b), [false, false, true]);
+}
+
+#[test]
+fn all_and_any() {
+    let positive = Vector3::new(1.0, 2.0, 3.0);
+    let mixed = Vector3::new(1.0, -2.0, 3.0);
+
+    assert!(positive.all(|c| c > 0.0));
+    assert!(!mixed.all(|c| c > 0.0));
+    assert!(mixed.any(|c| c < 0.0));
+    assert!(!positive.any(|c| c < 0.0));
+}
+
+#[test]
+fn constrain_axes() {
+    let velocity = Vector3::new(1.5, -4.3, 2.7);
+
+    assert_eq!(velocity.constrain_axes([true, false, false]), Vector3::new(1.5, 0.0, 0.0));
+}
+
+#[test]
+fn snap_to_nearest_axis() {
+    assert_eq!(Vector3::new(0.9, 0.3, -0.2).snap_to_nearest_axis(), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(Vector3::new(0.1, -0.8, 0.5).snap_to_nearest_axis(), Vector3::new(0.0, -1.0, 0.0));
+    assert_eq!(Vector3::new(0.4, 0.2, 2.0).snap_to_nearest_axis(), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(Vector3::new(-3.0, 1.0, 1.0).snap_to_nearest_axis(), Vector3::new(-1.0, 0.0, 0.0));
+    assert_eq!(Vector3::new_zero().snap_to_nearest_axis(), Vector3::new_zero());
+}
+
+#[test]
+fn move_towards() {
+    let current = Vector3::new(0.0, 0.0, 0.0);
+    let target = Vector3::new(3.0, 4.0, 0.0);
+
+    assert_eq!(current.move_towards(&target, 2.5), Vector3::new(1.5, 2.0, 0.0));
+    assert_eq!(current.move_towards(&target, 10.0), target);
+}
+
+#[test]
+fn smooth_damp() {
+    let target = Vector3::new(10.0, -5.0, 2.0);
+    let mut current = Vector3::new_zero();
+    let mut velocity = Vector3::new_zero();
+
+    for _ in 0..60 {
+        current = Vector3::smooth_damp(&current, &target, &mut velocity, 0.3, 1.0 / 60.0);
+    }
+    let speed_after_second: f64 = velocity.magnitude();
+
+    for _ in 0..240 {
+        current = Vector3::smooth_damp(&current, &target, &mut velocity, 0.3, 1.0 / 60.0);
+    }
+
+    assert!((current - target).magnitude() < 1e-4);
+    assert!(velocity.magnitude() < speed_after_second);
+    assert!(velocity.magnitude() < 1e-3);
+}
+
+#[test]
+fn normalize_range() {
+    let vector = Vector3::new(5.0, 50.0, 500.0);
+    let min = Vector3::new(0.0, 0.0, 0.0);
+    let max = Vector3::new(10.0, 100.0, 1000.0);
+
+    assert_eq!(vector.normalize_range(&min, &max), Vector3::new(0.5, 0.5, 0.5));
+    assert_eq!(vector.normalize_range(&min, &Vector3::new(10.0, 100.0, 0.0)), Vector3::new(0.5, 0.5, 0.0));
+}
+
+#[test]
+fn octahedral_and_back() {
+    let normals = [
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, -1.0, 0.0),
+        Vector3::new(1.0, 2.0, 3.0).normalize(),
+        Vector3::new(-0.5, 0.3, -0.8).normalize(),
+        Vector3::new(0.7, -0.7, -0.1).normalize()
+    ];
+
+    for normal in normals {
+        let encoded = normal.encode_octahedral();
+        assert!(encoded.0.abs() <= 1.0 && encoded.1.abs() <= 1.0);
+
+        let decoded = Vector3::decode_octahedral(encoded);
+        assert!((decoded - normal).magnitude() < 1e-12);
+    }
+}
+
+#[test]
+fn project_onto_plane_offset() {
+    let point = Vector3::new(1.0, 2.0, 5.0);
+    let plane_point = Vector3::new(0.0, 0.0, 0.0);
+    let plane_normal = Vector3::new(0.0, 0.0, 1.0);
+
+    assert_eq!(point.project_onto_plane_offset(&plane_point, &plane_normal, 0.01), Vector3::new(1.0, 2.0, 0.01));
+}
+
+#[test]
+fn isometric_and_back() {
+    assert_eq!(Vector3::new(0.0, 0.0, 0.0).to_isometric(), (0.0, 0.0));
+    assert_eq!(Vector3::new(1.0, 0.0, 0.0).to_isometric(), (1.0, 0.5));
+    assert_eq!(Vector3::new(0.0, 0.0, 1.0).to_isometric(), (-1.0, 0.5));
+    assert_eq!(Vector3::new(2.0, 3.0, 4.0).to_isometric(), (-2.0, 0.0));
+
+    let vector = Vector3::new(2.0, 3.0, 4.0);
+    assert_eq!(Vector3::from_isometric(vector.to_isometric(), vector.y), vector);
+}
+
+#[test]
+fn hermite() {
+    let p0 = Vector3::new(0.0, 0.0, 0.0);
+    let m0 = Vector3::new(1.0, 2.0, 0.0);
+    let p1 = Vector3::new(4.0, 1.0, -2.0);
+    let m1 = Vector3::new(0.0, -1.0, 3.0);
+
+    assert_eq!(Vector3::hermite(&p0, &m0, &p1, &m1, 0.0), p0);
+    assert_eq!(Vector3::hermite(&p0, &m0, &p1, &m1, 1.0), p1);
+    assert_eq!(Vector3::hermite(&p0, &m0, &p1, &m1, 0.5), Vector3::new(2.125, 0.875, -1.375));
+}
+
+#[test]
+fn reflect_planar_xy() {
+    let velocity = Vector3::new(1.0, -1.0, 0.25);
+
+    assert_eq!(velocity.reflect_planar_xy(&Vector3::new(0.0, 1.0, 0.0)), Vector3::new(1.0, 1.0, 0.25));
+    assert_eq!(velocity.reflect_planar_xy(&Vector3::new(-2.0, 0.0, 0.7)), Vector3::new(-1.0, -1.0, 0.25));
+}
+
+#[test]
+fn mean_direction() {
+    let cluster = [
+        Vector3::new(1.0, 0.1, 0.0),
+        Vector3::new(1.0, -0.1, 0.0),
+        Vector3::new(1.0, 0.0, 0.1),
+        Vector3::new(1.0, 0.0, -0.1)
+    ];
+    assert_eq!(Vector3::mean_direction(&cluster), Some(Vector3::new(1.0, 0.0, 0.0)));
+
+    let opposing = [Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, -1.0, 0.0)];
+    assert_eq!(Vector3::mean_direction(&opposing), None);
+    assert_eq!(Vector3::mean_direction(&[]), None);
+    assert_eq!(Vector3::mean_direction(&[Vector3::new(1e-17, 0.0, 0.0)]), Some(Vector3::new(1.0, 0.0, 0.0)));
+    assert_eq!(Vector3::mean_direction(&[Vector3::new(1e-17, 0.0, 0.0), Vector3::new(-1e-17, 0.0, 0.0)]), None);
+}
+
+#[test]
+fn rotation_vector_and_back() {
+    let axis = Vector3::new(0.0, 2.0, 0.0);
+    let rotation = Vector3::to_rotation_vector(&axis, 1.25);
+
+    assert_eq!(rotation, Vector3::new(0.0, 1.25, 0.0));
+    assert_eq!(rotation.from_rotation_vector(), (Vector3::new(0.0, 1.0, 0.0), 1.25));
+    assert_eq!(Vector3::new_zero().from_rotation_vector(), (Vector3::new_zero(), 0.0));
+}
+
+#[test]
+fn clamp_per_axis_abs() {
+    let vector = Vector3::new(5.0, -3.0, 2.0);
+
+    assert_eq!(vector.clamp_per_axis_abs(&Vector3::new(4.0, 4.0, 4.0)), Vector3::new(4.0, -3.0, 2.0));
+    assert_eq!(vector.clamp_per_axis_abs(&Vector3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, -2.0, 2.0));
+}
+
+#[test]
+fn lerp_hsv() {
+    let red = Vector3::new(1.0, 0.0, 0.0);
+    let green = Vector3::new(0.0, 1.0, 0.0);
+    let blue = Vector3::new(0.0, 0.0, 1.0);
+
+    assert!((Vector3::lerp_hsv(&red, &green, 0.5) - Vector3::new(1.0, 1.0, 0.0)).magnitude() < 1e-12);
+    assert!((Vector3::lerp_hsv(&red, &blue, 0.5) - Vector3::new(1.0, 0.0, 1.0)).magnitude() < 1e-12);
+    assert!((Vector3::lerp_hsv(&red, &green, 0.0) - red).magnitude() < 1e-12);
+    assert!((Vector3::lerp_hsv(&red, &green, 1.0) - green).magnitude() < 1e-12);
+
+    let black = Vector3::new(0.0, 0.0, 0.0);
+    assert!((Vector3::lerp_hsv(&black, &green, 0.5) - Vector3::new(0.25, 0.5, 0.25)).magnitude() < 1e-12);
+}
+
+#[test]
+fn saturate() {
+    let vector = Vector3::new(-0.5, 0.25, 1.5);
+
+    assert_eq!(vector.saturate(), Vector3::new(0.0, 0.25, 1.0));
+}
+
+#[test]
+fn reinhard() {
+    let bright = Vector3::new(1.0, 4.0, 99.0);
+
+    assert_eq!(bright.reinhard(), Vector3::new(0.5, 0.8, 0.99));
+    assert_eq!(Vector3::new(1.0, 4.0, 2.0).reinhard_extended(4.0), Vector3::new(0.53125, 1.0, 0.75));
+}
+
+#[test]
+fn fibonacci_sphere() {
+    let count: usize = 100;
+
+    for i in 0..count {
+        let point = Vector3::fibonacci_sphere(i, count);
+        assert!((point.magnitude() - 1.0).abs() < 1e-12);
+    }
+    assert!(Vector3::fibonacci_sphere(0, count).y > 0.9);
+    assert!(Vector3::fibonacci_sphere(count - 1, count).y < -0.9);
+}
+
+#[test]
+fn tangent_at() {
+    let north_pole = Vector3::new(0.0, 0.0, 1.0);
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(vector.tangent_at(&north_pole), Vector3::new(1.0, 2.0, 0.0));
+    assert_eq!(vector.tangent_at(&(north_pole * 5.0)), Vector3::new(1.0, 2.0, 0.0));
+}
+
+#[test]
+fn triangle_normal() {
+    let a = Vector3::new(0.0, 0.0, 0.0);
+    let b = Vector3::new(2.0, 0.0, 0.0);
+    let c = Vector3::new(0.0, 2.0, 0.0);
+
+    assert_eq!(super::triangle_normal(&a, &b, &c), Vector3::new(0.0, 0.0, 4.0));
+    assert_eq!(super::triangle_normal_normalized(&a, &b, &c), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(super::triangle_normal_normalized(&a, &b, &(b * 2.0)), Vector3::new_zero());
+
+    let start = Vector3::new(0.1, 0.2, 0.3);
+    let edge = Vector3::new(1.1, 2.3, 3.7);
+    assert_eq!(super::triangle_normal_normalized(&start, &(start + edge), &(start + edge * 3.3)), Vector3::new_zero());
+}
+
+#[test]
+fn bilinear() {
+    let c00 = Vector3::new(0.0, 0.0, 0.0);
+    let c10 = Vector3::new(1.0, 0.0, 2.0);
+    let c01 = Vector3::new(0.0, 1.0, 4.0);
+    let c11 = Vector3::new(1.0, 1.0, 8.0);
+
+    assert_eq!(Vector3::bilinear(&c00, &c10, &c01, &c11, 0.0, 0.0), c00);
+    assert_eq!(Vector3::bilinear(&c00, &c10, &c01, &c11, 1.0, 0.0), c10);
+    assert_eq!(Vector3::bilinear(&c00, &c10, &c01, &c11, 0.0, 1.0), c01);
+    assert_eq!(Vector3::bilinear(&c00, &c10, &c01, &c11, 1.0, 1.0), c11);
+    assert_eq!(Vector3::bilinear(&c00, &c10, &c01, &c11, 0.5, 0.5), Vector3::new(0.5, 0.5, 3.5));
+}
+
+#[test]
+fn assign_operators() {
+    let vector1 = Vector3 {
+        x: 1.5, y: -4.3, z: 2.7
+    };
+    let vector2 = Vector3 {
+        x: 1.5, y: -4.3, z: 2.8
+    };
+
+    let mut vector = vector1;
+    vector += vector2;
+    assert_eq!(vector, vector1 + vector2);
+
+    vector -= vector2;
+    assert_eq!(vector, vector1 + vector2 - vector2);
+
+    let mut vector = vector1;
+    vector *= 5.0;
+    assert_eq!(vector, vector1 * 5.0);
+
+    let mut vector = vector1;
+    vector *= vector2;
+    assert_eq!(vector, vector1 * vector2);
+
+    let mut vector = vector1;
+    vector /= 4.0;
+    assert_eq!(vector, vector1 / 4.0);
+}
+
+#[test]
+fn trilinear() {
+    let mut corners = [Vector3::new_zero(); 8];
+    for (i, corner) in corners.iter_mut().enumerate() {
+        *corner = Vector3::new(i as f64, (i * i) as f64, -(i as f64));
+    }
+
+    for (i, corner) in corners.iter().enumerate() {
+        let (u, v, w) = ((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64);
+        assert_eq!(Vector3::trilinear(&corners, u, v, w), *corner);
+    }
+    assert_eq!(Vector3::trilinear(&corners, 0.5, 0.5, 0.5), Vector3::new(3.5, 17.5, -3.5));
+}
+
+#[test]
+fn negate() {
+    let vector = Vector3::new(1.0, -2.0, 3.0);
+
+    assert_eq!(-vector, Vector3::new(-1.0, 2.0, -3.0));
+    assert_eq!(-&vector, Vector3::new(-1.0, 2.0, -3.0));
+    assert_eq!(-Vector3::new_zero(), Vector3::new_zero());
+}
+
+#[test]
+fn signed_angle_xy() {
+    let forward = Vector3::new(1.0, 0.0, 5.0);
+    let left = Vector3::new(0.0, 2.0, -1.0);
+
+    assert_eq!(forward.signed_angle_xy(&left), core::f64::consts::FRAC_PI_2);
+    assert_eq!(left.signed_angle_xy(&forward), -core::f64::consts::FRAC_PI_2);
+}
+
+#[test]
+fn index_axes() {
+    let mut vector = Vector3::new(1.5, -4.3, 2.7);
+
+    assert_eq!((vector[0], vector[1], vector[2]), (1.5, -4.3, 2.7));
+
+    for i in 0..3 {
+        vector[i] *= 2.0;
+    }
+    assert_eq!(vector, Vector3::new(3.0, -8.6, 5.4));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+fn index_out_of_bounds() {
+    let vector = Vector3::new_zero();
+
+    let _ = vector[3];
+}
+
+#[test]
+fn is_left_of_xy() {
+    let start = Vector3::new(0.0, 0.0, 0.0);
+    let end = Vector3::new(2.0, 0.0, 0.0);
+
+    assert!(Vector3::is_left_of_xy(&Vector3::new(1.0, 1.0, 3.0), &start, &end) > 0.0);
+    assert!(Vector3::is_left_of_xy(&Vector3::new(1.0, -1.0, 3.0), &start, &end) < 0.0);
+    assert_eq!(Vector3::is_left_of_xy(&Vector3::new(5.0, 0.0, 3.0), &start, &end), 0.0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn convex_hull_xy() {
+    let square = [
+        Vector3::new(1.0, 1.0, 5.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.5, 0.5, 2.0),
+        Vector3::new(1.0, 0.0, 3.0),
+        Vector3::new(0.0, 1.0, 4.0)
+    ];
+    assert_eq!(Vector3::convex_hull_xy(&square), vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0)
+    ]);
+
+    let collinear = [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(2.0, 2.0, 0.0),
+        Vector3::new(1.0, 1.0, 0.0),
+        Vector3::new(3.0, 3.0, 0.0)
+    ];
+    assert_eq!(Vector3::convex_hull_xy(&collinear), vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(3.0, 3.0, 0.0)]);
+}
+
+#[test]
+fn distance() {
+    let point_a = Vector3::new(1.0, 1.0, 2.0);
+    let point_b = Vector3::new(4.0, 5.0, 2.0);
+
+    assert_eq!(point_a.distance(&point_b), 5.0);
+    assert_eq!(point_a.distance_squared(&point_b), 25.0);
+
+    let point_c = Vector3::new(1.5, -4.3, 10.1);
+    let distance: f64 = point_a.distance(&point_c);
+    assert!((point_a.distance_squared(&point_c) - distance * distance).abs() < 1e-12);
+}
+
+#[test]
+fn polygon_area_and_centroid_xy() {
+    let square = [
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(1.0, 0.0, 1.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Vector3::new(0.0, 1.0, 1.0)
+    ];
+    assert_eq!(Vector3::polygon_area_xy(&square), 1.0);
+    assert_eq!(Vector3::polygon_centroid_xy(&square), Some(Vector3::new(0.5, 0.5, 0.0)));
+
+    let clockwise_triangle = [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.0, 3.0, 0.0),
+        Vector3::new(3.0, 0.0, 0.0)
+    ];
+    assert_eq!(Vector3::polygon_area_xy(&clockwise_triangle), -4.5);
+    assert_eq!(Vector3::polygon_centroid_xy(&clockwise_triangle), Some(Vector3::new(1.0, 1.0, 0.0)));
+
+    assert_eq!(Vector3::polygon_area_xy(&[]), 0.0);
+    assert_eq!(Vector3::polygon_centroid_xy(&[]), None);
+}
+
+#[test]
+fn lerp() {
+    let vector_a = Vector3::new(1.5, -4.3, 2.7);
+    let vector_b = Vector3::new(-3.5, 2.1, 10.1);
+
+    assert_eq!(vector_a.lerp(&vector_b, 0.0), vector_a);
+    assert_eq!(vector_a.lerp(&vector_b, 1.0), vector_b);
+    assert_eq!(Vector3::new(0.0, 2.0, 4.0).lerp(&Vector3::new(2.0, 4.0, -4.0), 0.5), Vector3::new(1.0, 3.0, 0.0));
+    assert_eq!(Vector3::new(0.0, 2.0, 4.0).lerp(&Vector3::new(2.0, 4.0, -4.0), 2.0), Vector3::new(4.0, 6.0, -12.0));
+
+    assert_eq!(vector_a.lerp_clamped(&vector_b, -1.0), vector_a);
+    assert_eq!(vector_a.lerp_clamped(&vector_b, 2.0), vector_b);
+}
+
+#[test]
+fn householder_matrix() {
+    let vector = Vector3::new(1.0, 2.0, 2.0) / 3.0;
+    let matrix = vector.householder_matrix();
+
+    let mut result = Vector3::new_zero();
+    for i in 0..3 {
+        result[i] = matrix[i][0] * vector.x + matrix[i][1] * vector.y + matrix[i][2] * vector.z;
+    }
+
+    assert!((result + vector).magnitude() < 1e-12);
+}
+
+#[test]
+fn jitter() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let amount: f64 = 0.01;
+
+    assert_eq!(vector.jitter(42, amount), vector.jitter(42, amount));
+    assert_ne!(vector.jitter(42, amount), vector.jitter(43, amount));
+
+    for seed in 0..100 {
+        let offset = vector.jitter(seed, amount) - vector;
+        assert!(offset.x.abs() <= amount && offset.y.abs() <= amount && offset.z.abs() <= amount);
+    }
+}
+
+#[test]
+fn reflect() {
+    let vector = Vector3::new(1.0, -1.0, 0.0);
+    let normal = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_eq!(vector.reflect(&normal), Vector3::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn edge_winding_xy() {
+    let point = Vector3::new(0.0, 0.0, 0.0);
+
+    assert_eq!(Vector3::edge_winding_xy(&point, &Vector3::new(1.0, 1.0, 0.0), &Vector3::new(2.0, 3.0, 0.0)), 0);
+    assert_eq!(Vector3::edge_winding_xy(&point, &Vector3::new(1.0, -3.0, 0.0), &Vector3::new(2.0, -1.0, 0.0)), 0);
+    assert_eq!(Vector3::edge_winding_xy(&point, &Vector3::new(1.0, -1.0, 0.0), &Vector3::new(1.0, 1.0, 0.0)), 1);
+    assert_eq!(Vector3::edge_winding_xy(&point, &Vector3::new(1.0, 1.0, 0.0), &Vector3::new(1.0, -1.0, 0.0)), -1);
+    assert_eq!(Vector3::edge_winding_xy(&point, &Vector3::new(-1.0, -1.0, 0.0), &Vector3::new(-1.0, 1.0, 0.0)), 0);
+
+    let square = [
+        Vector3::new(-1.0, -1.0, 0.0),
+        Vector3::new(1.0, -1.0, 0.0),
+        Vector3::new(1.0, 1.0, 0.0),
+        Vector3::new(-1.0, 1.0, 0.0)
+    ];
+    let winding = |point: &Vector3| -> i32 {
+        (0..4).map(|i| Vector3::edge_winding_xy(point, &square[i], &square[(i + 1) % 4])).sum()
+    };
+    assert_eq!(winding(&point), 1);
+    assert_eq!(winding(&Vector3::new(3.0, 0.0, 0.0)), 0);
+}
+
+#[test]
+fn min_max_clamp() {
+    let vector_a = Vector3::new(1.5, -4.3, 2.7);
+    let vector_b = Vector3::new(-1.5, 4.3, 2.8);
+
+    assert_eq!(vector_a.min(&vector_b), Vector3::new(-1.5, -4.3, 2.7));
+    assert_eq!(vector_a.max(&vector_b), Vector3::new(1.5, 4.3, 2.8));
+
+    let lo = Vector3::new(-1.0, -1.0, -1.0);
+    let hi = Vector3::new(1.0, 1.0, 1.0);
+    assert_eq!(Vector3::new(0.5, -4.3, 2.7).clamp(&lo, &hi), Vector3::new(0.5, -1.0, 1.0));
+
+    let with_nan = Vector3::new(f64::NAN, 1.0, 1.0);
+    assert_eq!(with_nan.min(&vector_a).x, f64::NAN.min(vector_a.x));
+    assert_eq!(with_nan.max(&vector_a).x, f64::NAN.max(vector_a.x));
+    assert_eq!(vector_a.min(&with_nan).x, vector_a.x.min(f64::NAN));
+}
+
+#[test]
+fn coefficient_along() {
+    let vector = Vector3::new(1.5, -4.3, 2.7);
+
+    assert_eq!(vector.coefficient_along(&Vector3::new(0.0, 1.0, 0.0)), -4.3);
+}
+
+#[test]
+fn pack_unorm_1010102_and_back() {
+    let vector = Vector3::new(0.25, 0.5, 0.9);
+    let step: f64 = 1.0 / 1023.0;
+
+    let (result, w) = Vector3::unpack_unorm_1010102(vector.pack_unorm_1010102(2));
+    assert_eq!(w, 2);
+    assert!((result.x - vector.x).abs() <= step / 2.0);
+    assert!((result.y - vector.y).abs() <= step / 2.0);
+    assert!((result.z - vector.z).abs() <= step / 2.0);
+
+    assert_eq!(Vector3::new(-1.0, 2.0, 1.0).pack_unorm_1010102(3), 0xFFFF_FC00);
+}
+
+#[test]
+fn relative_error() {
+    let reference = Vector3::new(3.0, 0.0, 4.0);
+
+    assert_eq!(reference.relative_error(&reference), 0.0);
+    assert_eq!(Vector3::new(3.0, 0.05, 4.0).relative_error(&reference), 0.01);
+    assert_eq!(Vector3::new_zero().relative_error(&Vector3::new_zero()), 0.0);
+    assert_eq!(reference.relative_error(&Vector3::new_zero()), f64::INFINITY);
+}
+
+#[test]
+fn axis_constants() {
+    assert_eq!(Vector3::X.cross(&Vector3::Y), Vector3::Z);
+    assert_eq!(Vector3::Y.cross(&Vector3::Z), Vector3::X);
+    assert_eq!(Vector3::Z.cross(&Vector3::X), Vector3::Y);
+    assert_eq!(Vector3::ZERO, Vector3::new_zero());
+    assert_eq!(Vector3::ONE, Vector3::new(1.0, 1.0, 1.0));
+
+    assert_eq!(Vector3::RIGHT.cross(&Vector3::UP), Vector3::BACK);
+    assert_eq!(Vector3::UP, -Vector3::DOWN);
+    assert_eq!(Vector3::LEFT, -Vector3::RIGHT);
+    assert_eq!(Vector3::FORWARD, -Vector3::BACK);
+}
+
+#[test]
+fn sanitize() {
+    let vector = Vector3::new(1.0, f64::NAN, f64::INFINITY);
+
+    assert_eq!(vector.sanitize(), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(Vector3::new(f64::NEG_INFINITY, -2.0, 3.0).sanitize(), Vector3::new(0.0, -2.0, 3.0));
+}
+
+#[test]
+fn approx_eq() {
+    let vector1 = Vector3 {
+        x: 1.5, y: -4.3, z: 2.7
+    };
+    let vector2 = Vector3 {
+        x: 1.5, y: -4.3, z: 2.8
+    };
+    let difference = vector1 - vector2;
+    let expected = Vector3::new(0.0, 0.0, -0.1);
+
+    assert_ne!(difference, expected);
+    assert!(difference.approx_eq(&expected, 1e-9));
+    assert!(!difference.approx_eq(&Vector3::new(0.0, 0.0, -0.2), 1e-9));
+    assert_eq!(vector1.abs_diff(&vector2), Vector3::new(0.0, 0.0, 0.09999999999999964));
+
+    let large = Vector3::new(1e12, -1e12, 1.0);
+    let nudged = Vector3::new(1e12 + 1.0, -1e12, 1.0);
+    assert!(!large.approx_eq(&nudged, 1e-9));
+    assert!(large.approx_eq_relative(&nudged, 1e-9));
+    assert!(!large.approx_eq_relative(&Vector3::new(1e12, -1e12, 1.1), 1e-9));
+}
+
+#[test]
+fn remap() {
+    let vector = Vector3::new(5.0, 15.0, 2.5);
+    let in_min = Vector3::new(0.0, 0.0, 0.0);
+    let in_max = Vector3::new(10.0, 10.0, 10.0);
+    let out_min = Vector3::new(-1.0, -1.0, 0.0);
+    let out_max = Vector3::new(1.0, 1.0, 100.0);
+
+    assert_eq!(vector.remap(&in_min, &in_max, &out_min, &out_max), Vector3::new(0.0, 2.0, 25.0));
+
+    let flat_max = Vector3::new(10.0, 0.0, 10.0);
+    assert_eq!(vector.remap(&in_min, &flat_max, &out_min, &out_max), Vector3::new(0.0, -1.0, 25.0));
+}
+
+#[test]
+fn array_and_tuple_conversions() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(Vector3::from([1.0, 2.0, 3.0]), vector);
+    assert_eq!(Vector3::from((1.0, 2.0, 3.0)), vector);
+    assert_eq!(vector.to_array(), [1.0, 2.0, 3.0]);
+
+    let array: [f64; 3] = vector.into();
+    assert_eq!(Vector3::from(array), vector);
+
+    let tuple: (f64, f64, f64) = vector.into();
+    assert_eq!(tuple, (1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from(tuple), vector);
+}
+
+#[test]
+fn clamp_magnitude_flagged() {
+    let vector = Vector3::new(3.0, 0.0, 4.0);
+
+    assert_eq!(vector.clamp_magnitude_flagged(10.0), (vector, false));
+    assert_eq!(vector.clamp_magnitude_flagged(2.5), (Vector3::new(1.5, 0.0, 2.0), true));
+}
+
+#[test]
+fn from_slice() {
+    assert_eq!(Vector3::from_slice(&[1.0, 2.0, 3.0]).unwrap(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap(), Vector3::new(1.0, 2.0, 3.0));
+    assert!(Vector3::from_slice(&[1.0, 2.0]).is_err());
+    assert!(Vector3::from_slice(&[]).is_err());
+}
+
+#[test]
+fn dominant_plane() {
+    assert_eq!(Vector3::new(-0.9, 0.1, 0.3).dominant_plane(), (1, 2));
+    assert_eq!(Vector3::new(0.2, 1.0, -0.4).dominant_plane(), (0, 2));
+    assert_eq!(Vector3::new(0.1, 0.2, -5.0).dominant_plane(), (0, 1));
+}
+
+#[test]
+fn display_and_parse() {
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(vector.to_string(), "(1, 2, 3)");
+    assert_eq!(vector.to_string().parse::<Vector3>(), Ok(vector));
+
+    let negative = Vector3::new(-1.5, -0.1, 0.30000000000000004);
+    assert_eq!(negative.to_string(), "(-1.5, -0.1, 0.30000000000000004)");
+    assert_eq!(negative.to_string().parse::<Vector3>(), Ok(negative));
+    assert_eq!(" ( 1,2 , 3 ) ".parse::<Vector3>(), Ok(vector));
+
+    assert_eq!("1, 2, 3".parse::<Vector3>(), Err(ParseVector3Error::MissingParentheses));
+    assert_eq!("(1, 2, 3".parse::<Vector3>(), Err(ParseVector3Error::MissingParentheses));
+    assert_eq!("(1, 2)".parse::<Vector3>(), Err(ParseVector3Error::WrongAxisCount(2)));
+    assert_eq!("(1, 2, 3, 4)".parse::<Vector3>(), Err(ParseVector3Error::WrongAxisCount(4)));
+    assert!(matches!("(1; 2; 3)".parse::<Vector3>(), Err(ParseVector3Error::InvalidAxis(_))));
+    assert!(matches!("(1, two, 3)".parse::<Vector3>(), Err(ParseVector3Error::InvalidAxis(_))));
+}
+
+#[test]
+fn running_stats() {
+    let samples = [
+        Vector3::new(1.0, -2.0, 10.0),
+        Vector3::new(4.0, 0.5, 12.0),
+        Vector3::new(-3.0, 7.0, 9.0),
+        Vector3::new(2.5, 1.0, 11.0)
+    ];
+
+    let mut stats = RunningStats::new();
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.variance(), None);
+
+    for sample in samples.iter() {
+        stats.push(sample);
+    }
+
+    let count = samples.len() as f64;
+    let mut mean = Vector3::new_zero();
+    for sample in samples.iter() {
+        mean += *sample;
+    }
+    mean /= count;
+    let mut variance = Vector3::new_zero();
+    for sample in samples.iter() {
+        variance += (*sample - mean) * (*sample - mean);
+    }
+    variance /= count;
+
+    assert_eq!(stats.count(), 4);
+    assert!(stats.mean().unwrap().approx_eq(&mean, 1e-12));
+    assert!(stats.variance().unwrap().approx_eq(&variance, 1e-12));
+}
+
+#[test]
+fn into_iter() {
+    let vector = Vector3::new(1.0, -2.0, 3.0);
+    assert_eq!(vector.into_iter().collect::<Vec<f64>>(), vec![1.0, -2.0, 3.0]);
+    assert_eq!((&vector).into_iter().collect::<Vec<f64>>(), vec![1.0, -2.0, 3.0]);
+    assert_eq!(vector.into_iter().map(|c| c * c).sum::<f64>(), 14.0);
+}
+
+#[test]
+fn snap_near_integers() {
+    let vector = Vector3::new(2.9999, 3.4, 5.0001);
+    assert_eq!(vector.snap_near_integers(0.01), Vector3::new(3.0, 3.4, 5.0));
+    assert_eq!(Vector3::new(-1.995, 0.5, -0.0001).snap_near_integers(0.01), Vector3::new(-2.0, 0.5, 0.0));
+    assert_eq!(vector.snap_near_integers(0.0), vector);
+}
+
+#[test]
+fn sum() {
+    let forces = vec![
+        Vector3::new(1.0, 0.0, -2.0),
+        Vector3::new(0.5, 3.0, 1.0),
+        Vector3::new(-4.0, 2.0, 0.25)
+    ];
+    let manual = forces.iter().fold(Vector3::new_zero(), |a, b| a + *b);
+
+    assert_eq!(forces.iter().sum::<Vector3>(), manual);
+    assert_eq!(forces.into_iter().sum::<Vector3>(), manual);
+    assert_eq!(Vec::<Vector3>::new().into_iter().sum::<Vector3>(), Vector3::new_zero());
+}
+
+#[test]
+fn reflect_pairwise() {
+    let velocities = [Vector3::new(1.0, -1.0, 0.0), Vector3::new(0.0, 2.0, 3.0), Vector3::new(-1.0, 0.5, 4.0)];
+    let normals = [Vector3::Y, Vector3::new(0.0, -1.0, 0.0), Vector3::new(1.0, 1.0, 1.0).normalize()];
+    let mut out = [Vector3::new_zero(); 3];
+
+    Vector3::reflect_pairwise(&velocities, &normals, &mut out);
+
+    for i in 0..3 {
+        assert_eq!(out[i], velocities[i].reflect(&normals[i]));
+    }
+    assert_eq!(out[0], Vector3::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+#[should_panic]
+fn reflect_pairwise_mismatched_lengths() {
+    let mut out = [Vector3::new_zero(); 2];
+    Vector3::reflect_pairwise(&[Vector3::X, Vector3::Y], &[Vector3::Z], &mut out);
+}
+
+#[test]
+fn map_and_zip_with() {
+    let a = Vector3::new(1.0, -4.0, 2.5);
+    let b = Vector3::new(-2.0, 3.0, 2.5);
+
+    assert_eq!(a.map(|c| c * c), a * a);
+    assert_eq!(a.map(|c| c + 1.0), Vector3::new(2.0, -3.0, 3.5));
+    assert_eq!(a.zip_with(&b, f64::max), a.max(&b));
+    assert_eq!(a.zip_with(&b, |l, r| l - r), a - b);
+}
+
+#[test]
+fn great_circle_distance() {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    assert!((Vector3::X.great_circle_distance(&Vector3::Y) - FRAC_PI_2).abs() < 1e-12);
+    assert_eq!(Vector3::Z.great_circle_distance(&Vector3::Z), 0.0);
+    assert!((Vector3::X.great_circle_distance(&-Vector3::X) - PI).abs() < 1e-12);
+
+    let nearby = Vector3::new(1.0, 1e-9, 0.0).normalize();
+    assert!((Vector3::X.great_circle_distance(&nearby) - 1e-9).abs() < 1e-18);
+}
+
+#[test]
+fn slerp() {
+    let (a, b) = (Vector3::X, Vector3::Y);
+    assert!(a.slerp(&b, 0.0).approx_eq(&a, 1e-12));
+    assert!(a.slerp(&b, 1.0).approx_eq(&b, 1e-12));
+
+    let half = core::f64::consts::FRAC_1_SQRT_2;
+    let middle = a.slerp(&b, 0.5);
+    assert!(middle.approx_eq(&Vector3::new(half, half, 0.0), 1e-12));
+    assert!((middle.magnitude() - 1.0).abs() < 1e-12);
+
+    // nearly parallel falls back to lerp
+    let close = Vector3::new(1.0, 1e-9, 0.0).normalize();
+    assert!(a.slerp(&close, 0.5).approx_eq(&a.lerp(&close, 0.5), 1e-12));
+
+    // antiparallel goes around through a perpendicular direction
+    let opposite = a.slerp(&-a, 0.5);
+    assert!((opposite.magnitude() - 1.0).abs() < 1e-12);
+    assert!(opposite.dot(&a).abs() < 1e-12);
+    assert!(a.slerp(&-a, 1.0).approx_eq(&-a, 1e-12));
+}
+
+#[test]
+fn spherical_midpoint() {
+    let half = core::f64::consts::FRAC_1_SQRT_2;
+    let midpoint = Vector3::X.spherical_midpoint(&Vector3::Y);
+    assert!(midpoint.approx_eq(&Vector3::new(half, half, 0.0), 1e-12));
+    assert!((Vector3::X.great_circle_distance(&midpoint) - midpoint.great_circle_distance(&Vector3::Y)).abs() < 1e-12);
+
+    let antipodal = Vector3::X.spherical_midpoint(&-Vector3::X);
+    assert!((antipodal.magnitude() - 1.0).abs() < 1e-12);
+    assert!(antipodal.dot(&Vector3::X).abs() < 1e-12);
+}
+
+#[test]
+fn rotate_around() {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    assert!(Vector3::X.rotate_around(&Vector3::Z, FRAC_PI_2).approx_eq(&Vector3::Y, 1e-12));
+    assert!(Vector3::X.rotate_around(&Vector3::Z, PI).approx_eq(&-Vector3::X, 1e-12));
+    assert!(Vector3::Z.rotate_around(&Vector3::Z, 1.0).approx_eq(&Vector3::Z, 1e-12));
+
+    let axis = Vector3::new(1.0, 1.0, 1.0).normalize();
+    assert!(Vector3::X.rotate_around(&axis, 2.0 * PI / 3.0).approx_eq(&Vector3::Y, 1e-12));
+}
+
+#[test]
+fn project_perspective() {
+    assert_eq!(Vector3::new(2.0, -1.0, -4.0).project_perspective(2.0), Some((1.0, -0.5)));
+    assert_eq!(Vector3::new(3.0, 3.0, -1.0).project_perspective(1.0), Some((3.0, 3.0)));
+    assert_eq!(Vector3::new(2.0, -1.0, 4.0).project_perspective(2.0), None);
+    assert_eq!(Vector3::new(2.0, -1.0, 0.0).project_perspective(2.0), None);
+}
+
+#[test]
+fn signed_angle() {
+    use core::f64::consts::FRAC_PI_2;
+
+    let (a, b) = (Vector3::X, Vector3::new(1.0, 1.0, 0.0));
+    assert!((a.signed_angle_radians(&b, &Vector3::Z) - a.angle_radians(&b)).abs() < 1e-12);
+    assert!((b.signed_angle_radians(&a, &Vector3::Z) + a.angle_radians(&b)).abs() < 1e-12);
+    assert!((a.signed_angle_degrees(&b, &Vector3::Z) - 45.0).abs() < 1e-12);
+    assert!((b.signed_angle_degrees(&a, &Vector3::Z) + 45.0).abs() < 1e-12);
+
+    assert!((Vector3::Y.signed_angle_radians(&Vector3::Z, &Vector3::X) - FRAC_PI_2).abs() < 1e-12);
+    assert!((Vector3::Y.signed_angle_radians(&Vector3::Z, &-Vector3::X) + FRAC_PI_2).abs() < 1e-12);
+}
+
+#[test]
+fn is_uniform() {
+    assert!(Vector3::new(2.0, 2.0, 2.0).is_uniform(0.0));
+    assert!(Vector3::new(2.0, 2.0001, 2.0).is_uniform(0.001));
+    assert!(!Vector3::new(2.0, 2.0001, 2.0).is_uniform(0.00001));
+    assert!(!Vector3::new(1.0, 2.0, 3.0).is_uniform(0.001));
+}
+
+#[test]
+fn angle_clamped() {
+    let vector = Vector3::new(0.1, 0.2, 0.3);
+    assert_eq!(vector.angle_radians(&vector), 0.0);
+    assert_eq!(vector.angle_degrees(&vector), 0.0);
+    assert_eq!(vector.angle_radians(&-vector), core::f64::consts::PI);
+
+    assert_eq!(vector.try_angle_radians(&vector), Some(0.0));
+    assert_eq!(vector.try_angle_radians(&Vector3::new_zero()), None);
+    assert_eq!(Vector3::new_zero().try_angle_radians(&vector), None);
+    assert!(vector.angle_radians(&Vector3::new_zero()).is_nan());
+}
+
+#[test]
+fn rotate_quarter_turns_xy() {
+    let vector = Vector3::new(0.1, -2.7, 3.0);
+    assert_eq!(Vector3::X.rotate_90_cw_xy(), Vector3::new(0.0, -1.0, 0.0));
+    assert_eq!(Vector3::X.rotate_90_ccw_xy(), Vector3::Y);
+    assert_eq!(vector.rotate_90_cw_xy().rotate_90_cw_xy().rotate_90_cw_xy().rotate_90_cw_xy(), vector);
+    assert_eq!(vector.rotate_90_ccw_xy().rotate_90_cw_xy(), vector);
+    assert_eq!(vector.rotate_90_cw_xy().rotate_90_cw_xy(), vector.rotate_180_xy());
+    assert_eq!(vector.rotate_180_xy(), Vector3::new(-0.1, 2.7, 3.0));
+}
+
+#[test]
+fn scalar_triple_and_project_on_plane() {
+    assert_eq!(Vector3::X.scalar_triple(&Vector3::Y, &Vector3::Z), 1.0);
+    assert_eq!(Vector3::X.scalar_triple(&Vector3::Z, &Vector3::Y), -1.0);
+    assert_eq!(Vector3::X.scalar_triple(&Vector3::X, &Vector3::Z), 0.0);
+
+    let vector = Vector3::new(1.0, 1.0, 1.0);
+    assert_eq!(vector.project_on_plane(&Vector3::Z), Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(vector.project_on_plane(&(Vector3::Z * 4.0)), Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(vector.project_on_plane(&Vector3::Z), vector.reject(&Vector3::Z));
+}
+
+#[test]
+fn clamp_largest_component() {
+    assert_eq!(Vector3::new(2.0, 10.0, 4.0).clamp_largest_component(5.0), Vector3::new(1.0, 5.0, 2.0));
+    assert_eq!(Vector3::new(2.0, -10.0, 4.0).clamp_largest_component(5.0), Vector3::new(1.0, -5.0, 2.0));
+    assert_eq!(Vector3::new(2.0, 3.0, 4.0).clamp_largest_component(5.0), Vector3::new(2.0, 3.0, 4.0));
+}
+
+#[test]
+fn div_entrywise() {
+    assert_eq!(Vector3::new(2.0, 4.0, 6.0) / Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 2.0, 2.0));
+
+    let quotient = Vector3::new(1.0, -1.0, 0.0) / Vector3::new(0.0, 0.0, 0.0);
+    assert_eq!(quotient.x, f64::INFINITY);
+    assert_eq!(quotient.y, f64::NEG_INFINITY);
+    assert!(quotient.z.is_nan());
+}
+
+#[test]
+fn slerp_all() {
+    let from = [Vector3::X, Vector3::Z, -Vector3::Y, Vector3::new(1.0, 1.0, 0.0).normalize()];
+    let mut out = [Vector3::new_zero(); 4];
+
+    Vector3::slerp_all(&from, &Vector3::Y, 0.3, &mut out);
+
+    for i in 0..from.len() {
+        assert_eq!(out[i], from[i].slerp(&Vector3::Y, 0.3));
+    }
+}
+
+#[test]
+#[should_panic]
+fn slerp_all_mismatched_lengths() {
+    let mut out = [Vector3::new_zero(); 1];
+    Vector3::slerp_all(&[Vector3::X, Vector3::Z], &Vector3::Y, 0.5, &mut out);
+}
+
+#[test]
+#[should_panic(expected = "fractional_bits must be less than 64")]
+fn fixed_point_too_many_bits() {
+    Vector3::new(1.0, 2.0, 3.0).to_fixed(64);
+}
+
+#[test]
+#[should_panic(expected = "fractional_bits must be less than 64")]
+fn from_fixed_too_many_bits() {
+    Vector3::from_fixed([1, 2, 3], 64);
+}
+
+#[test]
+#[should_panic(expected = "bits must be in 1..=32")]
+fn dequantize_zero_bits() {
+    Vector3::dequantize([0, 0, 0], &Vector3::new_zero(), &Vector3::ONE, 0);
+}
+
+#[test]
+#[should_panic(expected = "bits must be in 1..=32")]
+fn quantize_too_many_bits() {
+    Vector3::ONE.quantize(&Vector3::new_zero(), &Vector3::ONE, 64);
+}
+
+#[test]
+#[should_panic(expected = "index must be less than count")]
+fn fibonacci_sphere_index_out_of_range() {
+    Vector3::fibonacci_sphere(5, 3);
+}